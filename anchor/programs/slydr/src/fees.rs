@@ -0,0 +1,562 @@
+use anchor_lang::prelude::*;
+
+use crate::ErrorCode;
+
+// Fees are expressed in basis points, where 10000 is 100%
+pub const MAX_BASIS_POINTS: u16 = 10_000;
+
+// How a single payment is divided between the parties of a sale
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PaymentSplit {
+    pub platform_fee: u64,
+    pub royalty: u64,
+    pub seller_amount: u64,
+}
+
+impl PaymentSplit {
+    pub fn total(&self) -> Result<u64> {
+        self.platform_fee
+            .checked_add(self.royalty)
+            .and_then(|sum| sum.checked_add(self.seller_amount))
+            .ok_or_else(|| error!(ErrorCode::ArithmeticOverflow))
+    }
+}
+
+// Platform fee for a payment of `amount` lamports, clamped to the configured bounds
+pub fn platform_fee(
+    amount: u64,
+    fee_bps: u16,
+    min_fee: Option<u64>,
+    max_fee: Option<u64>,
+) -> Result<u64> {
+    require!(fee_bps <= MAX_BASIS_POINTS, ErrorCode::InvalidFeeAmount);
+
+    let fee = basis_points_of(amount, fee_bps)?;
+    let fee = match min_fee {
+        Some(min_fee) => fee.max(min_fee),
+        None => fee,
+    };
+    let fee = match max_fee {
+        Some(max_fee) => fee.min(max_fee),
+        None => fee,
+    };
+
+    Ok(fee)
+}
+
+// Creator royalty owed on a resale at `price`
+pub fn royalty(price: u64, royalty_percentage: u8) -> Result<u64> {
+    require!(royalty_percentage <= 100, ErrorCode::InvalidRoyaltyPercentage);
+
+    let royalty = (price as u128)
+        .checked_mul(royalty_percentage as u128)
+        .ok_or(ErrorCode::ArithmeticOverflow)?
+        / 100;
+
+    u64::try_from(royalty).map_err(|_| error!(ErrorCode::ArithmeticOverflow))
+}
+
+// Split for a primary sale or rental: the platform takes its fee and the creator gets the rest
+pub fn split_primary_sale(price: u64, platform_fee: u64) -> Result<PaymentSplit> {
+    let seller_amount = price
+        .checked_sub(platform_fee)
+        .ok_or(ErrorCode::PriceBelowFees)?;
+
+    Ok(PaymentSplit {
+        platform_fee,
+        royalty: 0,
+        seller_amount,
+    })
+}
+
+// Split for a resale: the creator takes a royalty, the platform its fee and the seller the rest
+pub fn split_resale(price: u64, royalty_percentage: u8, platform_fee: u64) -> Result<PaymentSplit> {
+    let royalty = royalty(price, royalty_percentage)?;
+    let seller_amount = price
+        .checked_sub(royalty)
+        .and_then(|remaining| remaining.checked_sub(platform_fee))
+        .ok_or(ErrorCode::PriceBelowFees)?;
+
+    Ok(PaymentSplit {
+        platform_fee,
+        royalty,
+        seller_amount,
+    })
+}
+
+// Value of the unused part of a `period`-second term bought for `price`
+pub fn prorated_credit(price: u64, period: i64, remaining: i64) -> Result<u64> {
+    require!(period > 0, ErrorCode::InvalidSubscriptionPeriod);
+
+    if remaining <= 0 {
+        return Ok(0);
+    }
+
+    let remaining = remaining.min(period);
+    let credit = (price as u128)
+        .checked_mul(remaining as u128)
+        .ok_or(ErrorCode::ArithmeticOverflow)?
+        / period as u128;
+
+    u64::try_from(credit).map_err(|_| error!(ErrorCode::ArithmeticOverflow))
+}
+
+// Prepaid value and length of a term after buying `period` more seconds for `price`, when
+// `remaining` seconds are left of a term that cost `paid_price` for `paid_period` seconds
+pub fn extend_prepaid_term(
+    paid_price: u64,
+    paid_period: i64,
+    remaining: i64,
+    price: u64,
+    period: i64,
+) -> Result<(u64, i64)> {
+    let remaining = remaining.clamp(0, paid_period.max(0));
+    let carried = if remaining > 0 {
+        prorated_credit(paid_price, paid_period, remaining)?
+    } else {
+        0
+    };
+    let term = remaining
+        .checked_add(period)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+    Ok((checked_add(carried, price)?, term))
+}
+
+// Seconds of a `period`-second term priced at `price` that `credit` lamports buy
+pub fn credit_to_time(credit: u64, price: u64, period: i64) -> Result<i64> {
+    require!(price > 0, ErrorCode::InvalidPrice);
+    require!(period > 0, ErrorCode::InvalidSubscriptionPeriod);
+
+    let time = (credit as u128)
+        .checked_mul(period as u128)
+        .ok_or(ErrorCode::ArithmeticOverflow)?
+        / price as u128;
+
+    i64::try_from(time).map_err(|_| error!(ErrorCode::ArithmeticOverflow))
+}
+
+// Epochs after a revenue epoch closes during which creators can claim their share
+pub const CLAIM_WINDOW_EPOCHS: u64 = 3;
+
+// Creators can claim from a closed epoch until its claim window has passed
+pub fn claim_window_open(epoch: u64, current_epoch: u64) -> bool {
+    current_epoch <= epoch.saturating_add(CLAIM_WINDOW_EPOCHS)
+}
+
+// Revenue of a closed pool that no creator can claim any more: all of it when nothing
+// was accessed, the rounding dust once every access has been claimed, or whatever is
+// left once the claim window has passed
+pub fn unclaimable_revenue(
+    total_revenue: u64,
+    total_paid_out: u64,
+    total_accesses: u64,
+    claimed_accesses: u64,
+    claim_window_open: bool,
+) -> Result<u64> {
+    require!(
+        !claim_window_open || claimed_accesses >= total_accesses,
+        ErrorCode::RevenueStillClaimable
+    );
+
+    total_revenue
+        .checked_sub(total_paid_out)
+        .ok_or_else(|| error!(ErrorCode::ArithmeticOverflow))
+}
+
+// Share of `total` owed for `part` out of `whole` units, rounded down
+pub fn pro_rata_share(total: u64, part: u64, whole: u64) -> Result<u64> {
+    if whole == 0 {
+        return Ok(0);
+    }
+    require!(part <= whole, ErrorCode::ArithmeticOverflow);
+
+    let share = (total as u128)
+        .checked_mul(part as u128)
+        .ok_or(ErrorCode::ArithmeticOverflow)?
+        / whole as u128;
+
+    u64::try_from(share).map_err(|_| error!(ErrorCode::ArithmeticOverflow))
+}
+
+// Price at `now` of a sale declining from `start_price` at `start_time` to `floor_price` at
+// `end_time`, either continuously or in steps of `step_interval` seconds when it is non-zero
+pub fn declining_price(
+    start_price: u64,
+    floor_price: u64,
+    start_time: i64,
+    end_time: i64,
+    step_interval: i64,
+    now: i64,
+) -> Result<u64> {
+    require!(
+        floor_price <= start_price && start_time < end_time && step_interval >= 0,
+        ErrorCode::InvalidDecliningPrice
+    );
+
+    if now <= start_time {
+        return Ok(start_price);
+    }
+    if now >= end_time {
+        return Ok(floor_price);
+    }
+
+    let duration = (end_time - start_time) as u128;
+    let mut elapsed = (now - start_time) as u128;
+    if step_interval > 0 {
+        elapsed -= elapsed % step_interval as u128;
+    }
+
+    let decline = (start_price - floor_price) as u128 * elapsed / duration;
+    Ok(start_price - decline as u64)
+}
+
+// Divides `amount` by basis-point `shares` summing to 10000; rounding dust goes to the first share
+pub fn split_by_shares(amount: u64, shares: &[u16]) -> Result<Vec<u64>> {
+    let total_shares = shares.iter().map(|share| *share as u32).sum::<u32>();
+    require!(
+        !shares.is_empty() && total_shares == MAX_BASIS_POINTS as u32,
+        ErrorCode::InvalidRevenueSplits
+    );
+
+    let mut amounts = shares
+        .iter()
+        .map(|share| basis_points_of(amount, *share))
+        .collect::<Result<Vec<u64>>>()?;
+
+    let distributed = amounts.iter().map(|part| *part as u128).sum::<u128>();
+    amounts[0] += (amount as u128 - distributed) as u64;
+
+    Ok(amounts)
+}
+
+// Overflow-checked addition for running totals
+pub fn checked_add(a: u64, b: u64) -> Result<u64> {
+    a.checked_add(b)
+        .ok_or_else(|| error!(ErrorCode::ArithmeticOverflow))
+}
+
+fn basis_points_of(amount: u64, bps: u16) -> Result<u64> {
+    let value = (amount as u128)
+        .checked_mul(bps as u128)
+        .ok_or(ErrorCode::ArithmeticOverflow)?
+        / MAX_BASIS_POINTS as u128;
+
+    u64::try_from(value).map_err(|_| error!(ErrorCode::ArithmeticOverflow))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn platform_fee_is_proportional_to_amount() {
+        assert_eq!(platform_fee(1_000_000, 250, None, None).unwrap(), 25_000);
+        assert_eq!(platform_fee(1_000_000, 0, None, None).unwrap(), 0);
+        assert_eq!(platform_fee(1_000_000, MAX_BASIS_POINTS, None, None).unwrap(), 1_000_000);
+    }
+
+    #[test]
+    fn platform_fee_rounds_down() {
+        assert_eq!(platform_fee(99, 100, None, None).unwrap(), 0);
+        assert_eq!(platform_fee(199, 100, None, None).unwrap(), 1);
+    }
+
+    #[test]
+    fn platform_fee_is_clamped_to_bounds() {
+        assert_eq!(platform_fee(1_000, 250, Some(5_000), None).unwrap(), 5_000);
+        assert_eq!(platform_fee(1_000_000_000, 250, None, Some(10_000)).unwrap(), 10_000);
+        assert_eq!(platform_fee(1_000_000, 250, Some(5_000), Some(50_000)).unwrap(), 25_000);
+    }
+
+    #[test]
+    fn platform_fee_rejects_more_than_100_percent() {
+        assert!(platform_fee(1_000, MAX_BASIS_POINTS + 1, None, None).is_err());
+    }
+
+    #[test]
+    fn platform_fee_handles_max_amount() {
+        assert_eq!(platform_fee(u64::MAX, MAX_BASIS_POINTS, None, None).unwrap(), u64::MAX);
+    }
+
+    #[test]
+    fn royalty_is_a_percentage_of_price() {
+        assert_eq!(royalty(1_000, 10).unwrap(), 100);
+        assert_eq!(royalty(1_000, 0).unwrap(), 0);
+        assert_eq!(royalty(1_000, 100).unwrap(), 1_000);
+        assert_eq!(royalty(u64::MAX, 100).unwrap(), u64::MAX);
+        assert!(royalty(1_000, 101).is_err());
+    }
+
+    #[test]
+    fn primary_sale_pays_creator_the_remainder() {
+        let split = split_primary_sale(1_000, 25).unwrap();
+        assert_eq!(split, PaymentSplit { platform_fee: 25, royalty: 0, seller_amount: 975 });
+    }
+
+    #[test]
+    fn primary_sale_rejects_fee_above_price() {
+        assert_eq!(
+            split_primary_sale(10, 11).unwrap_err(),
+            ErrorCode::PriceBelowFees.into()
+        );
+    }
+
+    #[test]
+    fn resale_pays_royalty_fee_and_seller() {
+        let split = split_resale(1_000, 10, 25).unwrap();
+        assert_eq!(split, PaymentSplit { platform_fee: 25, royalty: 100, seller_amount: 875 });
+    }
+
+    #[test]
+    fn resale_rejects_fees_above_price() {
+        assert_eq!(
+            split_resale(1_000, 100, 1).unwrap_err(),
+            ErrorCode::PriceBelowFees.into()
+        );
+    }
+
+    #[test]
+    fn checked_add_reports_overflow() {
+        assert_eq!(checked_add(1, 2).unwrap(), 3);
+        assert_eq!(
+            checked_add(u64::MAX, 1).unwrap_err(),
+            ErrorCode::ArithmeticOverflow.into()
+        );
+    }
+
+    #[test]
+    fn prorated_credit_covers_unused_time() {
+        assert_eq!(prorated_credit(1_000, 100, 25).unwrap(), 250);
+        assert_eq!(prorated_credit(1_000, 100, 0).unwrap(), 0);
+        assert_eq!(prorated_credit(1_000, 100, -10).unwrap(), 0);
+        assert_eq!(prorated_credit(1_000, 100, 500).unwrap(), 1_000);
+        assert!(prorated_credit(1_000, 0, 10).is_err());
+    }
+
+    #[test]
+    fn renewals_carry_the_unused_prepaid_value() {
+        // Half of a 1_000-lamport, 100-second term is left when another term is bought
+        assert_eq!(extend_prepaid_term(1_000, 100, 50, 1_000, 100).unwrap(), (1_500, 150));
+        // Three stacked terms credit all of their time, not just one period
+        let (paid_price, paid_period) = extend_prepaid_term(1_000, 100, 100, 1_000, 100).unwrap();
+        let (paid_price, paid_period) =
+            extend_prepaid_term(paid_price, paid_period, 200, 1_000, 100).unwrap();
+        assert_eq!((paid_price, paid_period), (3_000, 300));
+        assert_eq!(prorated_credit(paid_price, paid_period, 250).unwrap(), 2_500);
+        // A lapsed term carries nothing over
+        assert_eq!(extend_prepaid_term(1_000, 100, -5, 2_000, 100).unwrap(), (2_000, 100));
+    }
+
+    #[test]
+    fn credit_to_time_converts_lamports_to_seconds() {
+        assert_eq!(credit_to_time(250, 1_000, 100).unwrap(), 25);
+        assert_eq!(credit_to_time(0, 1_000, 100).unwrap(), 0);
+        assert!(credit_to_time(250, 0, 100).is_err());
+        assert!(credit_to_time(250, 1_000, 0).is_err());
+    }
+
+    #[test]
+    fn only_unclaimable_revenue_is_released() {
+        // Nobody accessed tiered content, so the whole pool is unclaimable
+        assert_eq!(unclaimable_revenue(1_000, 0, 0, 0, true).unwrap(), 1_000);
+        // Three creators with one access each leave one lamport of dust
+        assert_eq!(unclaimable_revenue(1_000, 999, 3, 3, true).unwrap(), 1);
+        // Already swept or fully paid pools have nothing left
+        assert_eq!(unclaimable_revenue(1_000, 1_000, 3, 3, true).unwrap(), 0);
+        // A creator still has a claim outstanding
+        assert_eq!(
+            unclaimable_revenue(1_000, 333, 3, 1, true).unwrap_err(),
+            ErrorCode::RevenueStillClaimable.into()
+        );
+    }
+
+    #[test]
+    fn creators_who_never_claim_do_not_lock_the_pool() {
+        // Epoch 10 closes at epoch 11 and stays claimable through epoch 13
+        assert!(!claim_window_open(10, 10 + CLAIM_WINDOW_EPOCHS + 1));
+        assert!(claim_window_open(10, 10 + CLAIM_WINDOW_EPOCHS));
+        assert!(claim_window_open(u64::MAX, u64::MAX));
+
+        // One of three creators claimed; the other two never do
+        assert_eq!(
+            unclaimable_revenue(1_000, 333, 3, 1, claim_window_open(10, 13)).unwrap_err(),
+            ErrorCode::RevenueStillClaimable.into()
+        );
+        assert_eq!(
+            unclaimable_revenue(1_000, 333, 3, 1, claim_window_open(10, 14)).unwrap(),
+            667
+        );
+    }
+
+    #[test]
+    fn pro_rata_share_splits_by_weight() {
+        assert_eq!(pro_rata_share(1_000, 1, 4).unwrap(), 250);
+        assert_eq!(pro_rata_share(1_000, 4, 4).unwrap(), 1_000);
+        assert_eq!(pro_rata_share(1_000, 0, 4).unwrap(), 0);
+        assert_eq!(pro_rata_share(1_000, 0, 0).unwrap(), 0);
+        assert_eq!(pro_rata_share(1_000, 1, 3).unwrap(), 333);
+        assert!(pro_rata_share(1_000, 5, 4).is_err());
+    }
+
+    #[test]
+    fn declining_price_is_pinned_at_the_window_edges() {
+        assert_eq!(declining_price(1_000, 100, 100, 200, 0, 0).unwrap(), 1_000);
+        assert_eq!(declining_price(1_000, 100, 100, 200, 0, 99).unwrap(), 1_000);
+        assert_eq!(declining_price(1_000, 100, 100, 200, 0, 100).unwrap(), 1_000);
+        assert_eq!(declining_price(1_000, 100, 100, 200, 0, 101).unwrap(), 991);
+        assert_eq!(declining_price(1_000, 100, 100, 200, 0, 199).unwrap(), 109);
+        assert_eq!(declining_price(1_000, 100, 100, 200, 0, 200).unwrap(), 100);
+        assert_eq!(declining_price(1_000, 100, 100, 200, 0, 201).unwrap(), 100);
+    }
+
+    #[test]
+    fn declining_price_falls_linearly() {
+        assert_eq!(declining_price(1_000, 100, 100, 200, 0, 150).unwrap(), 550);
+        assert_eq!(declining_price(1_000, 100, 100, 200, 0, 125).unwrap(), 775);
+    }
+
+    #[test]
+    fn declining_price_falls_in_steps() {
+        // 30 second steps over a 100 second window: drops at 130, 160 and 190
+        assert_eq!(declining_price(1_000, 100, 100, 200, 30, 100).unwrap(), 1_000);
+        assert_eq!(declining_price(1_000, 100, 100, 200, 30, 129).unwrap(), 1_000);
+        assert_eq!(declining_price(1_000, 100, 100, 200, 30, 130).unwrap(), 730);
+        assert_eq!(declining_price(1_000, 100, 100, 200, 30, 159).unwrap(), 730);
+        assert_eq!(declining_price(1_000, 100, 100, 200, 30, 160).unwrap(), 460);
+        assert_eq!(declining_price(1_000, 100, 100, 200, 30, 190).unwrap(), 190);
+        assert_eq!(declining_price(1_000, 100, 100, 200, 30, 199).unwrap(), 190);
+        assert_eq!(declining_price(1_000, 100, 100, 200, 30, 200).unwrap(), 100);
+    }
+
+    #[test]
+    fn declining_price_rejects_invalid_windows() {
+        assert!(declining_price(100, 1_000, 100, 200, 0, 150).is_err());
+        assert!(declining_price(1_000, 100, 200, 200, 0, 150).is_err());
+        assert!(declining_price(1_000, 100, 100, 200, -1, 150).is_err());
+    }
+
+    #[test]
+    fn split_by_shares_gives_dust_to_the_first_share() {
+        assert_eq!(split_by_shares(1_000, &[5_000, 5_000]).unwrap(), vec![500, 500]);
+        assert_eq!(split_by_shares(1_000, &[3_333, 3_333, 3_334]).unwrap(), vec![334, 333, 333]);
+        assert_eq!(split_by_shares(1, &[5_000, 5_000]).unwrap(), vec![1, 0]);
+        assert_eq!(split_by_shares(1_000, &[MAX_BASIS_POINTS]).unwrap(), vec![1_000]);
+    }
+
+    #[test]
+    fn split_by_shares_requires_shares_to_sum_to_100_percent() {
+        assert!(split_by_shares(1_000, &[]).is_err());
+        assert!(split_by_shares(1_000, &[5_000, 4_999]).is_err());
+        assert!(split_by_shares(1_000, &[5_000, 5_001]).is_err());
+    }
+
+    proptest! {
+        #[test]
+        fn platform_fee_never_exceeds_amount_without_min(
+            amount in any::<u64>(),
+            bps in 0..=MAX_BASIS_POINTS,
+            max_fee in proptest::option::of(any::<u64>()),
+        ) {
+            prop_assert!(platform_fee(amount, bps, None, max_fee).unwrap() <= amount);
+        }
+
+        #[test]
+        fn platform_fee_respects_bounds(
+            amount in any::<u64>(),
+            bps in 0..=MAX_BASIS_POINTS,
+            min_fee in 0..=u64::MAX / 2,
+            extra in 0..=u64::MAX / 2,
+        ) {
+            let max_fee = min_fee + extra;
+            let fee = platform_fee(amount, bps, Some(min_fee), Some(max_fee)).unwrap();
+            prop_assert!(fee >= min_fee && fee <= max_fee);
+        }
+
+        #[test]
+        fn prorated_credit_never_exceeds_price(
+            price in any::<u64>(),
+            period in 1..=i64::MAX,
+            remaining in any::<i64>(),
+        ) {
+            prop_assert!(prorated_credit(price, period, remaining).unwrap() <= price);
+        }
+
+        #[test]
+        fn pro_rata_shares_never_exceed_total(
+            total in any::<u64>(),
+            parts in proptest::collection::vec(0u64..1_000_000, 1..20),
+        ) {
+            let whole: u64 = parts.iter().sum();
+            let mut paid: u128 = 0;
+            for part in &parts {
+                paid += pro_rata_share(total, *part, whole).unwrap() as u128;
+            }
+            prop_assert!(paid <= total as u128);
+        }
+
+        #[test]
+        fn declining_price_stays_between_floor_and_start(
+            floor_price in any::<u64>(),
+            extra in any::<u64>(),
+            start_time in -1_000_000i64..1_000_000,
+            duration in 1i64..1_000_000,
+            step_interval in 0i64..1_000_000,
+            now in -2_000_000i64..2_000_000,
+        ) {
+            let start_price = floor_price.saturating_add(extra);
+            let price = declining_price(
+                start_price,
+                floor_price,
+                start_time,
+                start_time + duration,
+                step_interval,
+                now,
+            ).unwrap();
+            prop_assert!(price >= floor_price && price <= start_price);
+        }
+
+        #[test]
+        fn split_by_shares_parts_sum_to_amount(
+            amount in any::<u64>(),
+            cuts in proptest::collection::vec(0u16..=MAX_BASIS_POINTS, 0..7),
+        ) {
+            let mut cuts = cuts;
+            cuts.push(0);
+            cuts.push(MAX_BASIS_POINTS);
+            cuts.sort_unstable();
+            let shares: Vec<u16> = cuts.windows(2).map(|pair| pair[1] - pair[0]).collect();
+
+            let amounts = split_by_shares(amount, &shares).unwrap();
+            prop_assert_eq!(amounts.iter().map(|part| *part as u128).sum::<u128>(), amount as u128);
+        }
+
+        #[test]
+        fn primary_sale_parts_sum_to_price(price in any::<u64>(), fee in any::<u64>()) {
+            match split_primary_sale(price, fee) {
+                Ok(split) => prop_assert_eq!(split.total().unwrap(), price),
+                Err(err) => {
+                    prop_assert!(fee > price);
+                    prop_assert_eq!(err, ErrorCode::PriceBelowFees.into());
+                }
+            }
+        }
+
+        #[test]
+        fn resale_parts_sum_to_price(
+            price in any::<u64>(),
+            royalty_percentage in 0u8..=100,
+            bps in 0..=MAX_BASIS_POINTS,
+        ) {
+            let fee = platform_fee(price, bps, None, None).unwrap();
+            match split_resale(price, royalty_percentage, fee) {
+                Ok(split) => prop_assert_eq!(split.total().unwrap(), price),
+                Err(err) => {
+                    prop_assert!(royalty(price, royalty_percentage).unwrap() as u128 + fee as u128 > price as u128);
+                    prop_assert_eq!(err, ErrorCode::PriceBelowFees.into());
+                }
+            }
+        }
+    }
+}
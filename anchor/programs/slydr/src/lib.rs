@@ -1,6 +1,32 @@
 use anchor_lang::prelude::*;
-use anchor_lang::solana_program::program::invoke;
+use anchor_lang::solana_program::program::{invoke, invoke_signed};
 use anchor_lang::solana_program::system_instruction;
+use anchor_lang::system_program::{self, CreateAccount};
+use anchor_spl::associated_token::{
+    self, get_associated_token_address, get_associated_token_address_with_program_id,
+    AssociatedToken,
+};
+use anchor_spl::metadata::mpl_token_metadata::types::{Collection, CollectionDetails, Creator, DataV2};
+use anchor_spl::metadata::{
+    self, CreateMasterEditionV3, CreateMetadataAccountsV3, FreezeDelegatedAccount, Metadata,
+    MetadataAccount, ThawDelegatedAccount, VerifySizedCollectionItem,
+};
+use anchor_spl::token::{self, Approve, Mint, MintTo, Token, TokenAccount, Transfer};
+use anchor_spl::token_2022::spl_token_2022::extension::{metadata_pointer, ExtensionType};
+use anchor_spl::token_2022::{self, spl_token_2022, Token2022};
+use anchor_spl::token_interface;
+use spl_token_metadata_interface::state::{Field, TokenMetadata};
+
+pub mod fees;
+pub mod nft;
+pub mod oracle;
+pub mod pause;
+pub mod rental;
+pub mod resale;
+#[cfg(test)]
+mod test_accounts;
+
+use fees::MAX_BASIS_POINTS;
 
 declare_id!("YOUR_PROGRAM_ID_HERE");
 
@@ -8,20 +34,35 @@ declare_id!("YOUR_PROGRAM_ID_HERE");
 pub mod slydr_program {
     use super::*;
 
-    pub fn initialize(ctx: Context<Initialize>, platform_fee: u64) -> Result<()> {
+    pub fn initialize(
+        ctx: Context<Initialize>,
+        platform_fee_bps: u16,
+        min_platform_fee: Option<u64>,
+        max_platform_fee: Option<u64>,
+    ) -> Result<()> {
         // Input validation
-        require!(platform_fee > 0, ErrorCode::InvalidFeeAmount);
+        validate_platform_fee(platform_fee_bps, min_platform_fee, max_platform_fee)?;
 
         let platform = &mut ctx.accounts.platform;
         platform.authority = ctx.accounts.authority.key();
-        platform.platform_fee = platform_fee;
+        platform.pending_authority = None;
+        platform.platform_fee_bps = platform_fee_bps;
+        platform.min_platform_fee = min_platform_fee;
+        platform.max_platform_fee = max_platform_fee;
         platform.total_content_count = 0;
         platform.total_sales_volume = 0;
+        platform.total_withdrawn = 0;
+        platform.paused = 0;
+        platform.subscription_pool_balance = 0;
+        platform.price_oracle = None;
+        platform.accepted_mints = Vec::new();
 
         // Emit event
         emit!(PlatformInitialized {
             authority: ctx.accounts.authority.key(),
-            platform_fee,
+            platform_fee_bps,
+            min_platform_fee,
+            max_platform_fee,
             timestamp: Clock::get()?.unix_timestamp,
         });
 
@@ -34,16 +75,24 @@ pub mod slydr_program {
         arweave_id: String,
         price: u64,
         royalty_percentage: u8,
-        rental_enabled: bool,
-        rental_price: u64,
-        rental_duration: i64,
-        subscription_tier: u8,
+        launch: ContentLaunchOptions,
     ) -> Result<()> {
+        ctx.accounts.platform.require_not_paused(pause::PAUSE_CREATE_CONTENT)?;
+
+        let ContentLaunchOptions {
+            rental_enabled,
+            rental_price,
+            rental_duration,
+            subscription_tier,
+            max_supply,
+        } = launch;
+
         // Input validation
         require!(!content_id.is_empty(), ErrorCode::InvalidContentId);
         require!(!arweave_id.is_empty(), ErrorCode::InvalidArweaveId);
         require!(price > 0, ErrorCode::InvalidPrice);
         require!(royalty_percentage <= 100, ErrorCode::InvalidRoyaltyPercentage);
+        require!(max_supply != Some(0), ErrorCode::InvalidMaxSupply);
         
         // If rental is enabled, validate rental parameters
         if rental_enabled {
@@ -68,10 +117,23 @@ pub mod slydr_program {
         content.rental_price = rental_price;
         content.rental_duration = rental_duration;
         content.subscription_tier = subscription_tier;
+        content.declining_price = None;
+        content.max_supply = max_supply;
+        content.reserved_editions = 0;
+        content.revenue_splits = Vec::new();
+        content.revenue_splits_locked = false;
+        content.refund_window = None;
+        content.payment_mint = None;
+        content.usd_pricing = None;
+        content.nft_collection = None;
+
+        // Open the creator's earnings vault on their first piece of content
+        let creator_vault = &mut ctx.accounts.creator_vault;
+        creator_vault.creator = creator.key();
 
         // Increment platform content count
         let platform_data = &mut ctx.accounts.platform;
-        platform_data.total_content_count += 1;
+        platform_data.total_content_count = fees::checked_add(platform_data.total_content_count, 1)?;
 
         // Emit event
         emit!(ContentCreated {
@@ -81,6 +143,7 @@ pub mod slydr_program {
             royalty_percentage,
             rental_enabled,
             subscription_tier,
+            max_supply,
             timestamp: Clock::get()?.unix_timestamp,
         });
 
@@ -96,6 +159,8 @@ pub mod slydr_program {
         rental_duration: Option<i64>,
         subscription_tier: Option<u8>,
     ) -> Result<()> {
+        ctx.accounts.platform.require_not_paused(pause::PAUSE_UPDATE_CONTENT)?;
+
         let content = &mut ctx.accounts.content;
         
         // Only update fields that are provided
@@ -139,573 +204,6160 @@ pub mod slydr_program {
         Ok(())
     }
 
-    pub fn purchase_content(ctx: Context<PurchaseContent>) -> Result<()> {
+    pub fn set_declining_price(
+        ctx: Context<SetDecliningPrice>,
+        declining_price: Option<DecliningPrice>,
+    ) -> Result<()> {
+        ctx.accounts.platform.require_not_paused(pause::PAUSE_UPDATE_CONTENT)?;
+
+        // Input validation
+        if let Some(config) = &declining_price {
+            require!(config.floor_price > 0, ErrorCode::InvalidPrice);
+            require!(
+                config.start_price > config.floor_price
+                    && config.start_time < config.end_time
+                    && config.step_interval >= 0
+                    && config.step_interval < config.end_time - config.start_time,
+                ErrorCode::InvalidDecliningPrice
+            );
+        }
+
         let content = &mut ctx.accounts.content;
-        let buyer = &ctx.accounts.buyer;
-        let creator = &ctx.accounts.creator;
-        let platform = &mut ctx.accounts.platform;
-        let purchase = &mut ctx.accounts.purchase;
+        require!(
+            declining_price.is_none() || content.usd_pricing.is_none(),
+            ErrorCode::IncompatiblePricingModes
+        );
+        content.declining_price = declining_price.clone();
 
-        // Check if content is active
-        require!(content.active, ErrorCode::ContentNotActive);
+        // Emit event
+        emit!(DecliningPriceUpdated {
+            content_id: content.id.clone(),
+            creator: content.creator,
+            declining_price,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
 
-        // Transfer funds using helper function
-        transfer_funds(
-            buyer.to_account_info(),
-            creator.to_account_info(),
-            platform.to_account_info(),
-            content.price,
-            platform.platform_fee,
-            &[
-                buyer.to_account_info(),
-                creator.to_account_info(),
-                platform.to_account_info(),
-            ],
-        )?;
+        Ok(())
+    }
 
-        // Update content stats
-        content.sales_count += 1;
+    pub fn set_refund_window(ctx: Context<SetRefundWindow>, refund_window: Option<i64>) -> Result<()> {
+        ctx.accounts.platform.require_not_paused(pause::PAUSE_UPDATE_CONTENT)?;
 
-        // Update platform stats
-        platform.total_sales_volume += content.price;
+        // Input validation
+        require!(
+            refund_window.map_or(true, |window| window > 0),
+            ErrorCode::InvalidRefundWindow
+        );
 
-        // Record purchase
-        purchase.buyer = buyer.key();
-        purchase.content = content.key();
-        purchase.price = content.price;
-        purchase.timestamp = Clock::get()?.unix_timestamp;
-        purchase.resale_rights = true;
-        purchase.purchase_type = PurchaseType::FullPurchase;
-        purchase.expiration = None;
+        let content = &mut ctx.accounts.content;
+
+        // Refund escrow only holds lamports
+        require!(
+            refund_window.is_none() || content.payment_mint.is_none(),
+            ErrorCode::TokenRefundsUnsupported
+        );
+
+        content.refund_window = refund_window;
 
         // Emit event
-        emit!(ContentPurchased {
+        emit!(RefundWindowUpdated {
             content_id: content.id.clone(),
-            buyer: buyer.key(),
-            creator: creator.key(),
-            price: content.price,
+            creator: content.creator,
+            refund_window,
             timestamp: Clock::get()?.unix_timestamp,
         });
 
         Ok(())
     }
 
-    pub fn rent_content(ctx: Context<RentContent>) -> Result<()> {
+    pub fn set_usd_pricing(
+        ctx: Context<SetUsdPricing>,
+        usd_pricing: Option<UsdPricing>,
+    ) -> Result<()> {
+        ctx.accounts.platform.require_not_paused(pause::PAUSE_UPDATE_CONTENT)?;
+
+        // Input validation
+        if let Some(config) = &usd_pricing {
+            require!(
+                config.price_usd_cents > 0 && config.rental_price_usd_cents > 0,
+                ErrorCode::InvalidPrice
+            );
+        }
+
+        // The platform's feed must be live now, not only when first read
+        if usd_pricing.is_some() {
+            load_price_feed(
+                ctx.accounts.platform.price_oracle()?,
+                ctx.accounts.price_feed.as_ref(),
+            )?;
+        }
+
         let content = &mut ctx.accounts.content;
-        let renter = &ctx.accounts.renter;
-        let creator = &ctx.accounts.creator;
-        let platform = &mut ctx.accounts.platform;
-        let rental = &mut ctx.accounts.rental;
 
-        // Check if content is active and rental is enabled
-        require!(content.active, ErrorCode::ContentNotActive);
-        require!(content.rental_enabled, ErrorCode::RentalNotEnabled);
+        // USD prices convert to lamports on a fixed schedule of their own
+        require!(
+            usd_pricing.is_none()
+                || (content.payment_mint.is_none() && content.declining_price.is_none()),
+            ErrorCode::IncompatiblePricingModes
+        );
 
-        // Transfer funds using helper function
-        transfer_funds(
-            renter.to_account_info(),
-            creator.to_account_info(),
-            platform.to_account_info(),
-            content.rental_price,
-            platform.platform_fee,
-            &[
-                renter.to_account_info(),
-                creator.to_account_info(),
-                platform.to_account_info(),
-            ],
-        )?;
+        content.usd_pricing = usd_pricing.clone();
 
-        // Update platform stats
-        platform.total_sales_volume += content.rental_price;
+        // Emit event
+        emit!(UsdPricingUpdated {
+            content_id: content.id.clone(),
+            creator: content.creator,
+            usd_pricing,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
 
-        // Calculate expiration time
-        let current_time = Clock::get()?.unix_timestamp;
-        let expiration_time = current_time + content.rental_duration;
+        Ok(())
+    }
 
-        // Record rental
-        rental.buyer = renter.key();
-        rental.content = content.key();
-        rental.price = content.rental_price;
-        rental.timestamp = current_time;
-        rental.resale_rights = false;
-        rental.purchase_type = PurchaseType::Rental;
-        rental.expiration = Some(expiration_time);
+    pub fn set_payment_mint(ctx: Context<SetPaymentMint>, payment_mint: Option<Pubkey>) -> Result<()> {
+        let platform = &ctx.accounts.platform;
+        platform.require_not_paused(pause::PAUSE_UPDATE_CONTENT)?;
+
+        // Input validation
+        platform.require_accepted_mint(payment_mint)?;
+
+        let content = &mut ctx.accounts.content;
+
+        // Refund escrow only holds lamports
+        require!(
+            payment_mint.is_none() || content.refund_window.is_none(),
+            ErrorCode::TokenRefundsUnsupported
+        );
+        require!(
+            payment_mint.is_none() || content.usd_pricing.is_none(),
+            ErrorCode::IncompatiblePricingModes
+        );
+
+        content.payment_mint = payment_mint;
 
         // Emit event
-        emit!(ContentRented {
+        emit!(PaymentMintUpdated {
             content_id: content.id.clone(),
-            renter: renter.key(),
-            creator: creator.key(),
-            price: content.rental_price,
-            expiration: expiration_time,
-            timestamp: current_time,
+            creator: content.creator,
+            payment_mint,
+            timestamp: Clock::get()?.unix_timestamp,
         });
 
         Ok(())
     }
 
-    pub fn subscribe(ctx: Context<Subscribe>, tier: u8) -> Result<()> {
-        let subscriber = &ctx.accounts.subscriber;
-        let platform = &mut ctx.accounts.platform;
-        let subscription = &mut ctx.accounts.subscription;
-        
-        // Get subscription price based on tier
-        let subscription_price = match tier {
-            1 => 100_000_000, // 0.1 SOL for tier 1
-            2 => 200_000_000, // 0.2 SOL for tier 2
-            3 => 500_000_000, // 0.5 SOL for tier 3
-            _ => return Err(ErrorCode::InvalidSubscriptionTier.into()),
-        };
-        
-        // Transfer funds to platform
-        invoke(
-            &system_instruction::transfer(
-                subscriber.key,
-                platform.to_account_info().key,
-                subscription_price,
+    pub fn set_revenue_splits(
+        ctx: Context<SetRevenueSplits>,
+        revenue_splits: Vec<RevenueSplit>,
+        lock_after_first_sale: bool,
+    ) -> Result<()> {
+        ctx.accounts.platform.require_not_paused(pause::PAUSE_UPDATE_CONTENT)?;
+
+        let content = &mut ctx.accounts.content;
+
+        // Locked splits cannot change once the content has sold
+        require!(
+            !(content.revenue_splits_locked && content.sales_count > 0),
+            ErrorCode::RevenueSplitsLocked
+        );
+
+        // Input validation: an empty table pays the creator alone
+        require!(
+            revenue_splits.len() <= Content::MAX_REVENUE_SPLITS,
+            ErrorCode::InvalidRevenueSplits
+        );
+        if !revenue_splits.is_empty() {
+            let mut total_bps: u32 = 0;
+            for (index, split) in revenue_splits.iter().enumerate() {
+                require!(split.share_bps > 0, ErrorCode::InvalidRevenueSplits);
+                require!(
+                    revenue_splits[..index]
+                        .iter()
+                        .all(|other| other.recipient != split.recipient),
+                    ErrorCode::InvalidRevenueSplits
+                );
+                total_bps += split.share_bps as u32;
+            }
+            require!(
+                total_bps == MAX_BASIS_POINTS as u32,
+                ErrorCode::InvalidRevenueSplits
+            );
+        }
+
+        content.revenue_splits = revenue_splits.clone();
+        content.revenue_splits_locked = lock_after_first_sale;
+
+        // Emit event
+        emit!(RevenueSplitsUpdated {
+            content_id: content.id.clone(),
+            creator: content.creator,
+            revenue_splits,
+            locked: lock_after_first_sale,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    pub fn create_nft_collection(
+        ctx: Context<CreateNftCollection>,
+        name: String,
+        symbol: String,
+        uri: String,
+    ) -> Result<()> {
+        ctx.accounts.platform.require_not_paused(pause::PAUSE_UPDATE_CONTENT)?;
+
+        // Input validation, using Metaplex's field limits
+        require!(
+            !name.is_empty()
+                && name.len() <= nft::MAX_NAME_LEN
+                && symbol.len() <= nft::MAX_SYMBOL_LEN
+                && uri.len() <= nft::MAX_URI_LEN,
+            ErrorCode::InvalidNftMetadata
+        );
+
+        let content = &ctx.accounts.content;
+        let content_bump = [ctx.bumps.content];
+        let signer_seeds: &[&[&[u8]]] = &[&[b"content", content.id.as_bytes(), &content_bump]];
+
+        // The content PDA is mint and update authority of the collection
+        token::mint_to(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                MintTo {
+                    mint: ctx.accounts.collection_mint.to_account_info(),
+                    to: ctx.accounts.collection_token_account.to_account_info(),
+                    authority: content.to_account_info(),
+                },
+                signer_seeds,
             ),
-            &[
-                subscriber.to_account_info(),
-                platform.to_account_info(),
-            ],
+            1,
         )?;
-        
-        // Calculate expiration (30 days from now)
-        let current_time = Clock::get()?.unix_timestamp;
-        let expiration_time = current_time + 30 * 24 * 60 * 60; // 30 days in seconds
-        
-        // Record subscription
-        subscription.subscriber = subscriber.key();
-        subscription.tier = tier;
-        subscription.start_time = current_time;
-        subscription.expiration_time = expiration_time;
-        subscription.active = true;
-        
+        metadata::create_metadata_accounts_v3(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_metadata_program.to_account_info(),
+                CreateMetadataAccountsV3 {
+                    metadata: ctx.accounts.collection_metadata.to_account_info(),
+                    mint: ctx.accounts.collection_mint.to_account_info(),
+                    mint_authority: content.to_account_info(),
+                    payer: ctx.accounts.creator.to_account_info(),
+                    update_authority: content.to_account_info(),
+                    system_program: ctx.accounts.system_program.to_account_info(),
+                    rent: ctx.accounts.rent.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            DataV2 {
+                name: name.clone(),
+                symbol,
+                uri,
+                seller_fee_basis_points: content.royalty_percentage as u16 * 100,
+                creators: None,
+                collection: None,
+                uses: None,
+            },
+            true,
+            true,
+            Some(CollectionDetails::V1 { size: 0 }),
+        )?;
+        metadata::create_master_edition_v3(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_metadata_program.to_account_info(),
+                CreateMasterEditionV3 {
+                    edition: ctx.accounts.collection_master_edition.to_account_info(),
+                    mint: ctx.accounts.collection_mint.to_account_info(),
+                    update_authority: content.to_account_info(),
+                    mint_authority: content.to_account_info(),
+                    payer: ctx.accounts.creator.to_account_info(),
+                    metadata: ctx.accounts.collection_metadata.to_account_info(),
+                    token_program: ctx.accounts.token_program.to_account_info(),
+                    system_program: ctx.accounts.system_program.to_account_info(),
+                    rent: ctx.accounts.rent.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            Some(0),
+        )?;
+
+        let content = &mut ctx.accounts.content;
+        content.nft_collection = Some(ctx.accounts.collection_mint.key());
+
         // Emit event
-        emit!(SubscriptionCreated {
-            subscriber: subscriber.key(),
-            tier,
-            price: subscription_price,
-            expiration: expiration_time,
-            timestamp: current_time,
+        emit!(NftCollectionCreated {
+            content_id: content.id.clone(),
+            creator: content.creator,
+            collection_mint: ctx.accounts.collection_mint.key(),
+            name,
+            timestamp: Clock::get()?.unix_timestamp,
         });
-        
+
         Ok(())
     }
 
-    pub fn resell_content(ctx: Context<ResellContent>, price: u64) -> Result<()> {
+    pub fn mint_purchase_nft(ctx: Context<MintPurchaseNft>) -> Result<()> {
+        ctx.accounts.platform.require_not_paused(pause::PAUSE_PURCHASE)?;
+
         let content = &ctx.accounts.content;
-        let seller = &ctx.accounts.seller;
+        let purchase = &ctx.accounts.purchase;
+        let collection = &ctx.accounts.collection_metadata;
+
+        // Only a settled copy still held by the buyer can be minted, and only once
+        require!(purchase.transferred_to.is_none(), ErrorCode::PurchaseTransferred);
+        require!(purchase.refund_escrow.is_none(), ErrorCode::PurchaseInEscrow);
+        require!(purchase.nft_mint.is_none(), ErrorCode::NftAlreadyMinted);
+
+        let content_bump = [ctx.bumps.content];
+        let signer_seeds: &[&[&[u8]]] = &[&[b"content", content.id.as_bytes(), &content_bump]];
+
+        // Name the NFT after the collection and the copy's edition number
+        let name = nft::purchase_nft_name(
+            collection.name.trim_end_matches('\0'),
+            purchase.edition_number,
+        );
+
+        // Mint the single token to the buyer, then lock supply with a master edition
+        token::mint_to(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                MintTo {
+                    mint: ctx.accounts.nft_mint.to_account_info(),
+                    to: ctx.accounts.buyer_nft_account.to_account_info(),
+                    authority: content.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            1,
+        )?;
+        metadata::create_metadata_accounts_v3(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_metadata_program.to_account_info(),
+                CreateMetadataAccountsV3 {
+                    metadata: ctx.accounts.nft_metadata.to_account_info(),
+                    mint: ctx.accounts.nft_mint.to_account_info(),
+                    mint_authority: content.to_account_info(),
+                    payer: ctx.accounts.buyer.to_account_info(),
+                    update_authority: content.to_account_info(),
+                    system_program: ctx.accounts.system_program.to_account_info(),
+                    rent: ctx.accounts.rent.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            DataV2 {
+                name,
+                symbol: collection.symbol.trim_end_matches('\0').to_string(),
+                uri: collection.uri.trim_end_matches('\0').to_string(),
+                seller_fee_basis_points: content.royalty_percentage as u16 * 100,
+                creators: Some(vec![Creator {
+                    address: content.creator,
+                    verified: false,
+                    share: 100,
+                }]),
+                collection: Some(Collection {
+                    verified: false,
+                    key: ctx.accounts.collection_mint.key(),
+                }),
+                uses: None,
+            },
+            true,
+            true,
+            None,
+        )?;
+        metadata::create_master_edition_v3(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_metadata_program.to_account_info(),
+                CreateMasterEditionV3 {
+                    edition: ctx.accounts.nft_master_edition.to_account_info(),
+                    mint: ctx.accounts.nft_mint.to_account_info(),
+                    update_authority: content.to_account_info(),
+                    mint_authority: content.to_account_info(),
+                    payer: ctx.accounts.buyer.to_account_info(),
+                    metadata: ctx.accounts.nft_metadata.to_account_info(),
+                    token_program: ctx.accounts.token_program.to_account_info(),
+                    system_program: ctx.accounts.system_program.to_account_info(),
+                    rent: ctx.accounts.rent.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            Some(0),
+        )?;
+        metadata::verify_sized_collection_item(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_metadata_program.to_account_info(),
+                VerifySizedCollectionItem {
+                    payer: ctx.accounts.buyer.to_account_info(),
+                    metadata: ctx.accounts.nft_metadata.to_account_info(),
+                    collection_authority: content.to_account_info(),
+                    collection_mint: ctx.accounts.collection_mint.to_account_info(),
+                    collection_metadata: collection.to_account_info(),
+                    collection_master_edition: ctx.accounts.collection_master_edition.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            None,
+        )?;
+
+        // Lock the NFT in the buyer's account so it only changes hands through a listing
+        let nft = PurchaseNft::new(
+            content.to_account_info(),
+            ctx.accounts.nft_mint.key(),
+            Some(&ctx.accounts.nft_mint),
+            Some(&ctx.accounts.nft_master_edition),
+            Some(&ctx.accounts.token_program),
+            Some(&ctx.accounts.token_metadata_program),
+        )?;
+        nft.lock(
+            ctx.accounts.buyer.to_account_info(),
+            ctx.accounts.buyer_nft_account.to_account_info(),
+            signer_seeds,
+        )?;
+
+        let purchase = &mut ctx.accounts.purchase;
+        purchase.nft_mint = Some(ctx.accounts.nft_mint.key());
+
+        // Emit event
+        emit!(PurchaseNftMinted {
+            content_id: content.id.clone(),
+            purchase: purchase.key(),
+            buyer: purchase.buyer,
+            nft_mint: ctx.accounts.nft_mint.key(),
+            edition_number: purchase.edition_number,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    pub fn purchase_content<'info>(
+        ctx: Context<'_, '_, '_, 'info, PurchaseContent<'info>>,
+        max_price: Option<u64>,
+    ) -> Result<()> {
+        let content = &mut ctx.accounts.content;
         let buyer = &ctx.accounts.buyer;
-        let creator = &ctx.accounts.creator;
+        let creator_vault = &mut ctx.accounts.creator_vault;
         let platform = &mut ctx.accounts.platform;
-        let seller_purchase = &ctx.accounts.seller_purchase;
-        let buyer_purchase = &mut ctx.accounts.buyer_purchase;
+        let purchase = &mut ctx.accounts.purchase;
 
-        // Check if content is active
+        platform.require_not_paused(pause::PAUSE_PURCHASE)?;
+
+        // The wallet must not already own a copy
+        resale::check_purchase_reusable(purchase)?;
+
+        // Check if content is active and not sold out
         require!(content.active, ErrorCode::ContentNotActive);
-        
-        // Validate price
-        require!(price > 0, ErrorCode::InvalidPrice);
+        require!(content.has_unreserved_supply(), ErrorCode::ContentSoldOut);
 
-        // Check if seller has resale rights
+        // Fixed price, the current point on a declining price schedule, or the USD
+        // price converted at the price feed
+        let current_time = Clock::get()?.unix_timestamp;
+        let price = match &content.usd_pricing {
+            Some(usd_pricing) => usd_price_in_lamports(
+                usd_pricing.price_usd_cents,
+                platform.price_oracle()?,
+                ctx.accounts.price_feed.as_ref(),
+                current_time,
+            )?,
+            None => content.current_price(current_time)?,
+        };
         require!(
-            seller_purchase.resale_rights,
-            ErrorCode::NoResaleRights
+            max_price.map_or(true, |max_price| price <= max_price),
+            ErrorCode::PriceAboveMaximum
         );
-        
-        // Check if purchase is not expired (for rentals)
-        if let Some(expiration) = seller_purchase.expiration {
-            let current_time = Clock::get()?.unix_timestamp;
-            require!(current_time < expiration, ErrorCode::PurchaseExpired);
-        }
-
-        // Calculate royalty amount
-        let royalty_amount = (price * content.royalty_percentage as u64) / 100;
-        let platform_fee_amount = platform.platform_fee;
-        let seller_amount = price - royalty_amount - platform_fee_amount;
+        let platform_fee = platform.calculate_fee(price)?;
+        let split = fees::split_primary_sale(price, platform_fee)?;
 
-        // Transfer royalty to creator
-        invoke(
-            &system_instruction::transfer(buyer.key, creator.key, royalty_amount),
-            &[
-                buyer.to_account_info(),
-                creator.to_account_info(),
-            ],
-        )?;
-
-        // Transfer to seller
-        invoke(
-            &system_instruction::transfer(buyer.key, seller.key, seller_amount),
-            &[
-                buyer.to_account_info(),
-                seller.to_account_info(),
-            ],
-        )?;
+        // Hold the payment in the purchase account while a refund window is open,
+        // otherwise pay out immediately
+        let refund_escrow = match content.refund_window {
+            Some(refund_window) => {
+                transfer_funds(&buyer.to_account_info(), &[(purchase.to_account_info(), price)])?;
+                Some(RefundEscrow {
+                    platform_fee: split.platform_fee,
+                    release_after: current_time
+                        .checked_add(refund_window)
+                        .ok_or(ErrorCode::ArithmeticOverflow)?,
+                    status: RefundStatus::Open,
+                })
+            }
+            None => {
+                // Transfer funds using helper function
+                let route = PaymentRoute::new(
+                    content.payment_mint,
+                    buyer.to_account_info(),
+                    ctx.accounts.buyer_token_account.as_ref(),
+                    ctx.accounts.token_program.as_ref(),
+                )?;
+                let payout = CreatorPayout::new(
+                    content,
+                    content.payment_mint,
+                    route.recipient(
+                        &creator_vault.to_account_info(),
+                        ctx.accounts.creator_vault_token_account.as_ref(),
+                    )?,
+                    ctx.remaining_accounts,
+                );
+                let platform_recipient = route.recipient(
+                    &platform.to_account_info(),
+                    ctx.accounts.platform_token_account.as_ref(),
+                )?;
+                let payments = split_payments(&split, &payout, None, platform_recipient)?;
+                route.pay(&payments)?;
+                creator_vault.record_earnings(payout.payment_mint, payout.vault_earnings(&payments))?;
+                None
+            }
+        };
+        let refund_deadline = refund_escrow.as_ref().map(|escrow| escrow.release_after);
 
-        // Transfer platform fee
-        invoke(
-            &system_instruction::transfer(buyer.key, platform.to_account_info().key, platform_fee_amount),
-            &[
-                buyer.to_account_info(),
-                platform.to_account_info(),
-            ],
-        )?;
+        // Update content stats
+        let edition_number = content.record_sale()?;
 
         // Update platform stats
-        platform.total_sales_volume += price;
+        platform.record_sale_volume(content.payment_mint, price)?;
 
-        // Record purchase for buyer
-        buyer_purchase.buyer = buyer.key();
-        buyer_purchase.content = content.key();
-        buyer_purchase.price = price;
-        buyer_purchase.timestamp = Clock::get()?.unix_timestamp;
-        buyer_purchase.resale_rights = seller_purchase.resale_rights;
-        buyer_purchase.purchase_type = seller_purchase.purchase_type;
-        buyer_purchase.expiration = seller_purchase.expiration;
+        // Record purchase
+        purchase.buyer = buyer.key();
+        purchase.content = content.key();
+        purchase.price = price;
+        purchase.timestamp = current_time;
+        purchase.resale_rights = true;
+        purchase.purchase_type = PurchaseType::FullPurchase;
+        purchase.expiration = None;
+        purchase.acquired_from = None;
+        purchase.transferred_to = None;
+        purchase.edition_number = Some(edition_number);
+        purchase.refund_escrow = refund_escrow;
+        purchase.nft_mint = None;
+        purchase.pass_mint = None;
 
         // Emit event
-        emit!(ContentResold {
+        emit!(ContentPurchased {
             content_id: content.id.clone(),
-            seller: seller.key(),
             buyer: buyer.key(),
-            creator: creator.key(),
+            creator: content.creator,
             price,
-            royalty_amount,
-            timestamp: Clock::get()?.unix_timestamp,
+            platform_fee,
+            edition_number,
+            max_supply: content.max_supply,
+            refund_deadline,
+            payment_mint: content.payment_mint,
+            timestamp: current_time,
         });
 
         Ok(())
     }
-}
 
-// Helper function to transfer funds
-fn transfer_funds(
-    from: AccountInfo,
-    to_creator: AccountInfo,
-    to_platform: AccountInfo,
-    amount: u64,
-    platform_fee: u64,
-    accounts: &[AccountInfo],
-) -> Result<()> {
-    // Calculate amounts
-    let creator_amount = amount - platform_fee;
-    
-    // Transfer to creator
-    invoke(
-        &system_instruction::transfer(from.key, to_creator.key, creator_amount),
-        accounts,
-    )?;
-    
-    // Transfer platform fee
-    invoke(
-        &system_instruction::transfer(from.key, to_platform.key, platform_fee),
-        accounts,
-    )?;
-    
-    Ok(())
-}
+    pub fn request_refund(ctx: Context<RequestRefund>) -> Result<()> {
+        ctx.accounts.platform.require_not_paused(pause::PAUSE_REFUND)?;
 
-#[derive(Accounts)]
+        let purchase = &mut ctx.accounts.purchase;
+        let current_time = Clock::get()?.unix_timestamp;
+
+        // Only an open escrow still inside its window can be disputed
+        let escrow = purchase.refund_escrow_mut()?;
+        require!(escrow.status == RefundStatus::Open, ErrorCode::InvalidRefundStatus);
+        require!(current_time < escrow.release_after, ErrorCode::RefundWindowClosed);
+
+        // Give the creator a full response window to answer
+        escrow.status = RefundStatus::Requested;
+        escrow.extend_for_response(current_time)?;
+        let respond_by = escrow.release_after;
+
+        // Emit event
+        emit!(RefundRequested {
+            purchase: purchase.key(),
+            buyer: purchase.buyer,
+            content: purchase.content,
+            price: purchase.price,
+            respond_by,
+            timestamp: current_time,
+        });
+
+        Ok(())
+    }
+
+    pub fn approve_refund(ctx: Context<ApproveRefund>) -> Result<()> {
+        ctx.accounts.platform.require_not_paused(pause::PAUSE_REFUND)?;
+
+        let current_time = Clock::get()?.unix_timestamp;
+        let purchase_key = ctx.accounts.purchase.key();
+        let price = ctx.accounts.purchase.price;
+
+        // Creators may refund at any point while the payment is held
+        refund_purchase(
+            &mut ctx.accounts.purchase,
+            &ctx.accounts.buyer,
+            &mut ctx.accounts.content,
+            &mut ctx.accounts.platform,
+        )?;
+
+        // Emit event
+        emit!(RefundApproved {
+            purchase: purchase_key,
+            buyer: ctx.accounts.buyer.key(),
+            content: ctx.accounts.content.key(),
+            approved_by: ctx.accounts.creator.key(),
+            price,
+            timestamp: current_time,
+        });
+
+        Ok(())
+    }
+
+    pub fn reject_refund(ctx: Context<RejectRefund>) -> Result<()> {
+        ctx.accounts.platform.require_not_paused(pause::PAUSE_REFUND)?;
+
+        let purchase = &mut ctx.accounts.purchase;
+        let current_time = Clock::get()?.unix_timestamp;
+
+        // Give the buyer a full response window to escalate
+        let escrow = purchase.refund_escrow_mut()?;
+        require!(escrow.status == RefundStatus::Requested, ErrorCode::InvalidRefundStatus);
+        escrow.status = RefundStatus::Rejected;
+        escrow.extend_for_response(current_time)?;
+        let escalate_by = escrow.release_after;
+
+        // Emit event
+        emit!(RefundRejected {
+            purchase: purchase.key(),
+            buyer: purchase.buyer,
+            content: purchase.content,
+            escalate_by,
+            timestamp: current_time,
+        });
+
+        Ok(())
+    }
+
+    pub fn escalate_refund(ctx: Context<EscalateRefund>) -> Result<()> {
+        ctx.accounts.platform.require_not_paused(pause::PAUSE_REFUND)?;
+
+        let purchase = &mut ctx.accounts.purchase;
+        let current_time = Clock::get()?.unix_timestamp;
+
+        // A rejection can be escalated within its response window, and a request
+        // the creator never answered once that window has passed
+        let escrow = purchase.refund_escrow_mut()?;
+        match escrow.status {
+            RefundStatus::Rejected => {
+                require!(current_time < escrow.release_after, ErrorCode::RefundWindowClosed)
+            }
+            RefundStatus::Requested => {
+                require!(current_time >= escrow.release_after, ErrorCode::InvalidRefundStatus)
+            }
+            _ => return err!(ErrorCode::InvalidRefundStatus),
+        }
+        escrow.status = RefundStatus::Disputed;
+
+        // Emit event
+        emit!(RefundEscalated {
+            purchase: purchase.key(),
+            buyer: purchase.buyer,
+            content: purchase.content,
+            arbiter: ctx.accounts.platform.authority,
+            timestamp: current_time,
+        });
+
+        Ok(())
+    }
+
+    pub fn resolve_dispute(ctx: Context<ResolveDispute>, refund: bool) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp;
+        let purchase_key = ctx.accounts.purchase.key();
+
+        let escrow = ctx.accounts.purchase.refund_escrow_mut()?;
+        require!(escrow.status == RefundStatus::Disputed, ErrorCode::InvalidRefundStatus);
+
+        // The platform authority arbitrates: refund the buyer, or let the escrow be released
+        if refund {
+            refund_purchase(
+                &mut ctx.accounts.purchase,
+                &ctx.accounts.buyer,
+                &mut ctx.accounts.content,
+                &mut ctx.accounts.platform,
+            )?;
+        } else {
+            escrow.status = RefundStatus::Upheld;
+        }
+
+        // Emit event
+        emit!(DisputeResolved {
+            purchase: purchase_key,
+            buyer: ctx.accounts.buyer.key(),
+            arbiter: ctx.accounts.authority.key(),
+            refunded: refund,
+            timestamp: current_time,
+        });
+
+        Ok(())
+    }
+
+    pub fn release_escrow<'info>(
+        ctx: Context<'_, '_, '_, 'info, ReleaseEscrow<'info>>,
+    ) -> Result<()> {
+        let content = &ctx.accounts.content;
+        let creator_vault = &mut ctx.accounts.creator_vault;
+        let platform = &mut ctx.accounts.platform;
+        let purchase = &mut ctx.accounts.purchase;
+
+        platform.require_not_paused(pause::PAUSE_REFUND)?;
+
+        // Check the refund window has passed with no open request or dispute
+        let current_time = Clock::get()?.unix_timestamp;
+        let escrow = purchase.refund_escrow_mut()?.clone();
+        require!(escrow.is_releasable(current_time), ErrorCode::EscrowNotReleasable);
+
+        // Pay out of the escrow held by the purchase account
+        let split = fees::split_primary_sale(purchase.price, escrow.platform_fee)?;
+        let payout = CreatorPayout::new(content, None, creator_vault.to_account_info(), ctx.remaining_accounts);
+        let payments = split_payments(&split, &payout, None, platform.to_account_info())?;
+        transfer_funds_from_escrow(&purchase.to_account_info(), &payments)?;
+        creator_vault.record_earnings(payout.payment_mint, payout.vault_earnings(&payments))?;
+
+        purchase.refund_escrow = None;
+
+        // Emit event
+        emit!(EscrowReleased {
+            purchase: purchase.key(),
+            buyer: purchase.buyer,
+            creator: content.creator,
+            price: purchase.price,
+            platform_fee: split.platform_fee,
+            timestamp: current_time,
+        });
+
+        Ok(())
+    }
+
+    pub fn rent_content<'info>(
+        ctx: Context<'_, '_, '_, 'info, RentContent<'info>>,
+        max_price: Option<u64>,
+    ) -> Result<()> {
+        let content = &mut ctx.accounts.content;
+        let renter = &ctx.accounts.renter;
+        let creator_vault = &mut ctx.accounts.creator_vault;
+        let platform = &mut ctx.accounts.platform;
+        let rental = &mut ctx.accounts.rental;
+
+        platform.require_not_paused(pause::PAUSE_RENTAL)?;
+
+        // Check if content is active and rental is enabled
+        require!(content.active, ErrorCode::ContentNotActive);
+        require!(content.rental_enabled, ErrorCode::RentalNotEnabled);
+
+        // Fixed rental price, or the USD rental price converted at the price feed
+        let current_time = Clock::get()?.unix_timestamp;
+        let rental_price =
+            rental_price_at(content, platform, ctx.accounts.price_feed.as_ref(), current_time)?;
+        require!(
+            max_price.map_or(true, |max_price| rental_price <= max_price),
+            ErrorCode::PriceAboveMaximum
+        );
+
+        // A previous rental can only be replaced once it has expired and its pass is burned
+        rental::check_rerentable(rental, current_time)?;
+
+        let platform_fee = platform.calculate_fee(rental_price)?;
+        let split = fees::split_primary_sale(rental_price, platform_fee)?;
+
+        // Transfer funds using helper function
+        let route = PaymentRoute::new(
+            content.payment_mint,
+            renter.to_account_info(),
+            ctx.accounts.renter_token_account.as_ref(),
+            ctx.accounts.token_program.as_ref(),
+        )?;
+        let payout = CreatorPayout::new(
+            content,
+            content.payment_mint,
+            route.recipient(
+                &creator_vault.to_account_info(),
+                ctx.accounts.creator_vault_token_account.as_ref(),
+            )?,
+            ctx.remaining_accounts,
+        );
+        let platform_recipient = route.recipient(
+            &platform.to_account_info(),
+            ctx.accounts.platform_token_account.as_ref(),
+        )?;
+        let payments = split_payments(&split, &payout, None, platform_recipient)?;
+        route.pay(&payments)?;
+        creator_vault.record_earnings(payout.payment_mint, payout.vault_earnings(&payments))?;
+
+        // Update platform stats
+        platform.record_sale_volume(content.payment_mint, rental_price)?;
+
+        // Calculate expiration time
+        let expiration_time = current_time
+            .checked_add(content.rental_duration)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        // Record rental
+        rental.buyer = renter.key();
+        rental.content = content.key();
+        rental.price = rental_price;
+        rental.timestamp = current_time;
+        rental.resale_rights = false;
+        rental.purchase_type = PurchaseType::Rental;
+        rental.expiration = Some(expiration_time);
+        rental.acquired_from = None;
+        rental.transferred_to = None;
+        rental.refund_escrow = None;
+        rental.edition_number = None;
+        rental.nft_mint = None;
+        rental.pass_mint = None;
+
+        // Emit event
+        emit!(ContentRented {
+            content_id: content.id.clone(),
+            renter: renter.key(),
+            creator: content.creator,
+            price: rental_price,
+            platform_fee,
+            expiration: expiration_time,
+            payment_mint: content.payment_mint,
+            timestamp: current_time,
+        });
+
+        Ok(())
+    }
+
+    pub fn extend_rental<'info>(
+        ctx: Context<'_, '_, '_, 'info, ExtendRental<'info>>,
+        periods: u32,
+        max_price: Option<u64>,
+    ) -> Result<()> {
+        let content = &ctx.accounts.content;
+        let renter = &ctx.accounts.renter;
+        let creator_vault = &mut ctx.accounts.creator_vault;
+        let platform = &mut ctx.accounts.platform;
+        let rental = &mut ctx.accounts.rental;
+
+        platform.require_not_paused(pause::PAUSE_RENTAL)?;
+
+        // Check if content is active and rental is enabled
+        require!(content.active, ErrorCode::ContentNotActive);
+        require!(content.rental_enabled, ErrorCode::RentalNotEnabled);
+        require!(periods > 0, ErrorCode::InvalidRentalPeriods);
+
+        // Charge one rental price per period
+        let current_time = Clock::get()?.unix_timestamp;
+        let price = rental::extension_price(
+            rental_price_at(content, platform, ctx.accounts.price_feed.as_ref(), current_time)?,
+            periods,
+        )?;
+        require!(
+            max_price.map_or(true, |max_price| price <= max_price),
+            ErrorCode::PriceAboveMaximum
+        );
+
+        let platform_fee = platform.calculate_fee(price)?;
+        let split = fees::split_primary_sale(price, platform_fee)?;
+
+        // Transfer funds using helper function
+        let route = PaymentRoute::new(
+            content.payment_mint,
+            renter.to_account_info(),
+            ctx.accounts.renter_token_account.as_ref(),
+            ctx.accounts.token_program.as_ref(),
+        )?;
+        let payout = CreatorPayout::new(
+            content,
+            content.payment_mint,
+            route.recipient(
+                &creator_vault.to_account_info(),
+                ctx.accounts.creator_vault_token_account.as_ref(),
+            )?,
+            ctx.remaining_accounts,
+        );
+        let platform_recipient = route.recipient(
+            &platform.to_account_info(),
+            ctx.accounts.platform_token_account.as_ref(),
+        )?;
+        let payments = split_payments(&split, &payout, None, platform_recipient)?;
+        route.pay(&payments)?;
+        creator_vault.record_earnings(payout.payment_mint, payout.vault_earnings(&payments))?;
+
+        // Update platform stats
+        platform.record_sale_volume(content.payment_mint, price)?;
+
+        // Extend from the current expiration, or from now if it has already passed
+        let previous_expiration = rental.expiration.unwrap_or_default();
+        let expiration_time = rental::extended_expiration(
+            rental.expiration,
+            current_time,
+            content.rental_duration,
+            periods,
+        )?;
+        rental.expiration = Some(expiration_time);
+
+        // Keep the expiry on the rental pass in step with the rental
+        if let Some(pass_mint_key) = rental.pass_mint {
+            let (Some(pass_mint), Some(token_2022_program)) = (
+                ctx.accounts.pass_mint.as_ref(),
+                ctx.accounts.token_2022_program.as_ref(),
+            ) else {
+                return err!(ErrorCode::NoRentalPass);
+            };
+            require_keys_eq!(pass_mint.key(), pass_mint_key, ErrorCode::NoRentalPass);
+
+            // Top up rent in case the longer timestamp grows the metadata
+            let growth = expiration_time
+                .to_string()
+                .len()
+                .saturating_sub(previous_expiration.to_string().len());
+            let required = Rent::get()?.minimum_balance(pass_mint.data_len() + growth);
+            if pass_mint.lamports() < required {
+                system_program::transfer(
+                    CpiContext::new(
+                        ctx.accounts.system_program.to_account_info(),
+                        system_program::Transfer {
+                            from: renter.to_account_info(),
+                            to: pass_mint.to_account_info(),
+                        },
+                    ),
+                    required - pass_mint.lamports(),
+                )?;
+            }
+
+            let content_bump = [ctx.bumps.content];
+            invoke_signed(
+                &spl_token_metadata_interface::instruction::update_field(
+                    &token_2022_program.key(),
+                    &pass_mint_key,
+                    &content.key(),
+                    Field::Key(RENTAL_PASS_EXPIRY_KEY.to_string()),
+                    expiration_time.to_string(),
+                ),
+                &[pass_mint.to_account_info(), content.to_account_info()],
+                &[&[b"content", content.id.as_bytes(), &content_bump]],
+            )?;
+        }
+
+        // Emit event
+        emit!(RentalExtended {
+            content_id: content.id.clone(),
+            renter: renter.key(),
+            periods,
+            price,
+            platform_fee,
+            previous_expiration,
+            expiration: expiration_time,
+            payment_mint: content.payment_mint,
+            timestamp: current_time,
+        });
+
+        Ok(())
+    }
+
+    pub fn close_rental(ctx: Context<CloseRental>) -> Result<()> {
+        let rental = &ctx.accounts.rental;
+
+        // The renter can close at any time, anyone else only after expiry
+        let current_time = Clock::get()?.unix_timestamp;
+        let expired = rental.expiration.is_some_and(|expiration| current_time >= expiration);
+        require!(
+            expired || ctx.accounts.caller.key() == rental.buyer,
+            ErrorCode::RentalNotExpired
+        );
+        require!(rental.pass_mint.is_none(), ErrorCode::RentalPassOutstanding);
+
+        // Rent is returned to the renter when the rental account closes
+        emit!(RentalClosed {
+            rental: rental.key(),
+            renter: rental.buyer,
+            content: rental.content,
+            expired,
+            timestamp: current_time,
+        });
+
+        Ok(())
+    }
+
+    pub fn issue_rental_pass(ctx: Context<IssueRentalPass>) -> Result<()> {
+        ctx.accounts.platform.require_not_paused(pause::PAUSE_RENTAL)?;
+
+        let content = &ctx.accounts.content;
+        let renter = &ctx.accounts.renter;
+        let rental = &ctx.accounts.rental;
+        let pass_mint = &ctx.accounts.pass_mint;
+        let token_2022_program = &ctx.accounts.token_2022_program;
+
+        // Only a live rental without a pass can be issued one
+        require!(rental.pass_mint.is_none(), ErrorCode::RentalPassExists);
+        let current_time = Clock::get()?.unix_timestamp;
+        let expiration = rental.expiration.unwrap_or_default();
+        require!(current_time < expiration, ErrorCode::RentalExpired);
+
+        let content_bump = [ctx.bumps.content];
+        let content_seeds: &[&[&[u8]]] = &[&[b"content", content.id.as_bytes(), &content_bump]];
+        let rental_key = rental.key();
+        let mint_bump = [ctx.bumps.pass_mint];
+        let mint_seeds: &[&[&[u8]]] = &[&[b"rental_pass", rental_key.as_ref(), &mint_bump]];
+
+        // Fund the mint for its extensions plus the metadata written after initialization
+        let mint_key = pass_mint.key();
+        let content_key = content.key();
+        let metadata = rental_pass_metadata(content, mint_key, expiration);
+        let mint_len = ExtensionType::try_calculate_account_len::<spl_token_2022::state::Mint>(
+            &RENTAL_PASS_EXTENSIONS,
+        )?;
+        let lamports = Rent::get()?.minimum_balance(mint_len + metadata.tlv_size_of()?);
+        system_program::create_account(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                CreateAccount {
+                    from: renter.to_account_info(),
+                    to: pass_mint.to_account_info(),
+                },
+                mint_seeds,
+            ),
+            lamports,
+            mint_len as u64,
+            &token_2022::ID,
+        )?;
+
+        // Extensions must be initialized before the mint itself
+        let mint_info = pass_mint.to_account_info();
+        for ix in [
+            spl_token_2022::instruction::initialize_non_transferable_mint(&token_2022::ID, &mint_key)?,
+            spl_token_2022::instruction::initialize_permanent_delegate(
+                &token_2022::ID,
+                &mint_key,
+                &content_key,
+            )?,
+            spl_token_2022::instruction::initialize_mint_close_authority(
+                &token_2022::ID,
+                &mint_key,
+                Some(&content_key),
+            )?,
+            metadata_pointer::instruction::initialize(
+                &token_2022::ID,
+                &mint_key,
+                Some(content_key),
+                Some(mint_key),
+            )?,
+            spl_token_2022::instruction::initialize_mint2(
+                &token_2022::ID,
+                &mint_key,
+                &content_key,
+                None,
+                0,
+            )?,
+        ] {
+            invoke(&ix, std::slice::from_ref(&mint_info))?;
+        }
+
+        // Write the metadata, with the expiry as an additional field, into the mint
+        let metadata_accounts = [mint_info.clone(), content.to_account_info()];
+        invoke_signed(
+            &spl_token_metadata_interface::instruction::initialize(
+                &token_2022::ID,
+                &mint_key,
+                &content_key,
+                &mint_key,
+                &content_key,
+                metadata.name,
+                metadata.symbol,
+                metadata.uri,
+            ),
+            &metadata_accounts,
+            content_seeds,
+        )?;
+        invoke_signed(
+            &spl_token_metadata_interface::instruction::update_field(
+                &token_2022::ID,
+                &mint_key,
+                &content_key,
+                Field::Key(RENTAL_PASS_EXPIRY_KEY.to_string()),
+                expiration.to_string(),
+            ),
+            &metadata_accounts,
+            content_seeds,
+        )?;
+
+        // Mint the single pass into the renter's associated token account
+        associated_token::create(CpiContext::new(
+            ctx.accounts.associated_token_program.to_account_info(),
+            associated_token::Create {
+                payer: renter.to_account_info(),
+                associated_token: ctx.accounts.renter_pass_account.to_account_info(),
+                authority: renter.to_account_info(),
+                mint: mint_info.clone(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                token_program: token_2022_program.to_account_info(),
+            },
+        ))?;
+        token_2022::mint_to(
+            CpiContext::new_with_signer(
+                token_2022_program.to_account_info(),
+                token_2022::MintTo {
+                    mint: mint_info,
+                    to: ctx.accounts.renter_pass_account.to_account_info(),
+                    authority: content.to_account_info(),
+                },
+                content_seeds,
+            ),
+            1,
+        )?;
+
+        let rental = &mut ctx.accounts.rental;
+        rental.pass_mint = Some(mint_key);
+
+        // Emit event
+        emit!(RentalPassIssued {
+            content_id: content.id.clone(),
+            rental: rental_key,
+            renter: renter.key(),
+            pass_mint: mint_key,
+            expiration,
+            timestamp: current_time,
+        });
+
+        Ok(())
+    }
+
+    pub fn burn_expired_rental(ctx: Context<BurnExpiredRental>) -> Result<()> {
+        let content = &ctx.accounts.content;
+        let rental = &ctx.accounts.rental;
+        let pass_mint = &ctx.accounts.pass_mint;
+        let token_2022_program = &ctx.accounts.token_2022_program;
+
+        // Anyone can clean up a rental pass once the rental has expired
+        let current_time = Clock::get()?.unix_timestamp;
+        require!(
+            rental.expiration.is_some_and(|expiration| current_time >= expiration),
+            ErrorCode::RentalNotExpired
+        );
+
+        let content_bump = [ctx.bumps.content];
+        let content_seeds: &[&[&[u8]]] = &[&[b"content", content.id.as_bytes(), &content_bump]];
+
+        // Burn as permanent delegate, unless the renter already burned the pass
+        if let Some(pass_account) = &ctx.accounts.renter_pass_account {
+            if pass_account.amount > 0 {
+                token_2022::burn(
+                    CpiContext::new_with_signer(
+                        token_2022_program.to_account_info(),
+                        token_2022::Burn {
+                            mint: pass_mint.to_account_info(),
+                            from: pass_account.to_account_info(),
+                            authority: content.to_account_info(),
+                        },
+                        content_seeds,
+                    ),
+                    pass_account.amount,
+                )?;
+            }
+        }
+
+        // Close the empty mint to the renter; the rental account follows on exit
+        token_2022::close_account(CpiContext::new_with_signer(
+            token_2022_program.to_account_info(),
+            token_2022::CloseAccount {
+                account: pass_mint.to_account_info(),
+                destination: ctx.accounts.renter.to_account_info(),
+                authority: content.to_account_info(),
+            },
+            content_seeds,
+        ))?;
+
+        // Emit event
+        emit!(RentalPassBurned {
+            content_id: content.id.clone(),
+            rental: rental.key(),
+            renter: rental.buyer,
+            pass_mint: pass_mint.key(),
+            expiration: rental.expiration.unwrap_or_default(),
+            timestamp: current_time,
+        });
+
+        Ok(())
+    }
+
+    pub fn create_subscription_tier(
+        ctx: Context<CreateSubscriptionTier>,
+        tier: u8,
+        price: u64,
+        period: i64,
+        name: String,
+        payment_mint: Option<Pubkey>,
+    ) -> Result<()> {
+        // Input validation
+        require!(tier > 0, ErrorCode::InvalidSubscriptionTier);
+        require!(price > 0, ErrorCode::InvalidPrice);
+        require!(period > 0, ErrorCode::InvalidSubscriptionPeriod);
+        require!(
+            !name.is_empty() && name.len() <= SubscriptionTierConfig::MAX_NAME_LEN,
+            ErrorCode::InvalidTierName
+        );
+        ctx.accounts.platform.require_accepted_mint(payment_mint)?;
+
+        let tier_config = &mut ctx.accounts.tier_config;
+        tier_config.tier = tier;
+        tier_config.price = price;
+        tier_config.period = period;
+        tier_config.active = true;
+        tier_config.name = name.clone();
+        tier_config.payment_mint = payment_mint;
+
+        // Emit event
+        emit!(SubscriptionTierCreated {
+            tier,
+            price,
+            period,
+            name,
+            payment_mint,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    pub fn update_subscription_tier(
+        ctx: Context<UpdateSubscriptionTier>,
+        price: Option<u64>,
+        period: Option<i64>,
+        name: Option<String>,
+    ) -> Result<()> {
+        let tier_config = &mut ctx.accounts.tier_config;
+
+        // Only update fields that are provided
+        if let Some(new_price) = price {
+            require!(new_price > 0, ErrorCode::InvalidPrice);
+            tier_config.price = new_price;
+        }
+
+        if let Some(new_period) = period {
+            require!(new_period > 0, ErrorCode::InvalidSubscriptionPeriod);
+            tier_config.period = new_period;
+        }
+
+        if let Some(new_name) = name {
+            require!(
+                !new_name.is_empty() && new_name.len() <= SubscriptionTierConfig::MAX_NAME_LEN,
+                ErrorCode::InvalidTierName
+            );
+            tier_config.name = new_name;
+        }
+
+        // Emit event
+        emit!(SubscriptionTierUpdated {
+            tier: tier_config.tier,
+            price: tier_config.price,
+            period: tier_config.period,
+            name: tier_config.name.clone(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    pub fn retire_subscription_tier(ctx: Context<RetireSubscriptionTier>) -> Result<()> {
+        let tier_config = &mut ctx.accounts.tier_config;
+
+        // Check if tier is still offered
+        require!(tier_config.active, ErrorCode::SubscriptionTierInactive);
+
+        // Existing subscriptions keep running until they expire
+        tier_config.active = false;
+
+        // Emit event
+        emit!(SubscriptionTierRetired {
+            tier: tier_config.tier,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    pub fn subscribe(ctx: Context<Subscribe>, tier: u8) -> Result<()> {
+        let subscriber = &ctx.accounts.subscriber;
+        let platform = &mut ctx.accounts.platform;
+        let tier_config = &ctx.accounts.tier_config;
+        let subscription = &mut ctx.accounts.subscription;
+        let revenue_epoch = &mut ctx.accounts.revenue_epoch;
+
+        platform.require_not_paused(pause::PAUSE_SUBSCRIBE)?;
+
+        // Check if tier is still offered
+        require!(tier_config.active, ErrorCode::SubscriptionTierInactive);
+        
+        // Get subscription price from the tier config
+        let subscription_price = tier_config.price;
+
+        // Platform share of the subscription payment
+        let platform_fee = platform.calculate_fee(subscription_price)?;
+        
+        // Transfer funds to platform
+        let route = PaymentRoute::new(
+            tier_config.payment_mint,
+            subscriber.to_account_info(),
+            ctx.accounts.subscriber_token_account.as_ref(),
+            ctx.accounts.token_program.as_ref(),
+        )?;
+        let platform_recipient = route.recipient(
+            &platform.to_account_info(),
+            ctx.accounts.platform_token_account.as_ref(),
+        )?;
+        route.pay(&[(platform_recipient, subscription_price)])?;
+        
+        // Calculate expiration from the tier's billing period
+        let current_time = Clock::get()?.unix_timestamp;
+
+        // Add the creators' share to this epoch's revenue pool
+        accrue_subscription_revenue(
+            platform,
+            revenue_epoch,
+            ctx.accounts.token_revenue_pool.as_deref_mut(),
+            tier_config.payment_mint,
+            current_time,
+            subscription_price,
+            platform_fee,
+        )?;
+
+        let expiration_time = current_time
+            .checked_add(tier_config.period)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        
+        // Record subscription
+        subscription.subscriber = subscriber.key();
+        subscription.tier = tier;
+        subscription.start_time = current_time;
+        subscription.expiration_time = expiration_time;
+        subscription.active = true;
+        subscription.paid_price = subscription_price;
+        subscription.paid_period = tier_config.period;
+        
+        // Emit event
+        emit!(SubscriptionCreated {
+            subscriber: subscriber.key(),
+            tier,
+            price: subscription_price,
+            platform_fee,
+            expiration: expiration_time,
+            payment_mint: tier_config.payment_mint,
+            timestamp: current_time,
+        });
+        
+        Ok(())
+    }
+
+    pub fn renew_subscription(ctx: Context<RenewSubscription>) -> Result<()> {
+        let subscriber = &ctx.accounts.subscriber;
+        let platform = &mut ctx.accounts.platform;
+        let tier_config = &ctx.accounts.tier_config;
+        let subscription = &mut ctx.accounts.subscription;
+        let revenue_epoch = &mut ctx.accounts.revenue_epoch;
+
+        platform.require_not_paused(pause::PAUSE_SUBSCRIBE)?;
+
+        // Check if tier is still offered
+        require!(tier_config.active, ErrorCode::SubscriptionTierInactive);
+
+        let subscription_price = tier_config.price;
+        let platform_fee = platform.calculate_fee(subscription_price)?;
+
+        // Transfer funds to platform
+        let route = PaymentRoute::new(
+            tier_config.payment_mint,
+            subscriber.to_account_info(),
+            ctx.accounts.subscriber_token_account.as_ref(),
+            ctx.accounts.token_program.as_ref(),
+        )?;
+        let platform_recipient = route.recipient(
+            &platform.to_account_info(),
+            ctx.accounts.platform_token_account.as_ref(),
+        )?;
+        route.pay(&[(platform_recipient, subscription_price)])?;
+
+        // Add the creators' share to this epoch's revenue pool
+        let current_time = Clock::get()?.unix_timestamp;
+        accrue_subscription_revenue(
+            platform,
+            revenue_epoch,
+            ctx.accounts.token_revenue_pool.as_deref_mut(),
+            tier_config.payment_mint,
+            current_time,
+            subscription_price,
+            platform_fee,
+        )?;
+
+        // Extend from the current expiration, or start a new term if it has lapsed
+        let lapsed = !subscription.active || subscription.expiration_time <= current_time;
+        let renew_from = if lapsed {
+            current_time
+        } else {
+            subscription.expiration_time
+        };
+        let expiration_time = renew_from
+            .checked_add(tier_config.period)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        // Carry the value of the unused time into the new prepaid term
+        let (paid_price, paid_period) = fees::extend_prepaid_term(
+            subscription.paid_price,
+            subscription.paid_period,
+            subscription.remaining(current_time),
+            subscription_price,
+            tier_config.period,
+        )?;
+
+        // Update subscription
+        if lapsed {
+            subscription.start_time = current_time;
+        }
+        subscription.expiration_time = expiration_time;
+        subscription.active = true;
+        subscription.paid_price = paid_price;
+        subscription.paid_period = paid_period;
+
+        // Emit event
+        emit!(SubscriptionRenewed {
+            subscriber: subscriber.key(),
+            tier: subscription.tier,
+            price: subscription_price,
+            platform_fee,
+            expiration: expiration_time,
+            payment_mint: tier_config.payment_mint,
+            timestamp: current_time,
+        });
+
+        Ok(())
+    }
+
+    pub fn change_subscription_tier(
+        ctx: Context<ChangeSubscriptionTier>,
+        new_tier: u8,
+    ) -> Result<()> {
+        let subscriber = &ctx.accounts.subscriber;
+        let platform = &mut ctx.accounts.platform;
+        let current_tier_config = &ctx.accounts.current_tier_config;
+        let new_tier_config = &ctx.accounts.new_tier_config;
+        let subscription = &mut ctx.accounts.subscription;
+        let revenue_epoch = &mut ctx.accounts.revenue_epoch;
+
+        platform.require_not_paused(pause::PAUSE_SUBSCRIBE)?;
+
+        // Input validation
+        require!(new_tier != subscription.tier, ErrorCode::SameSubscriptionTier);
+        require!(new_tier_config.active, ErrorCode::SubscriptionTierInactive);
+        require!(
+            new_tier_config.payment_mint == current_tier_config.payment_mint,
+            ErrorCode::PaymentMintMismatch
+        );
+
+        // Credit all of the unused prepaid time, at the rate it was paid for
+        let current_time = Clock::get()?.unix_timestamp;
+        let credit = fees::prorated_credit(
+            subscription.paid_price,
+            subscription.paid_period,
+            subscription.remaining(current_time),
+        )?;
+
+        // Upgrades pay the difference, downgrades turn leftover credit into extra time
+        let (amount_charged, bonus_time) = if credit >= new_tier_config.price {
+            let leftover = credit - new_tier_config.price;
+            let bonus_time =
+                fees::credit_to_time(leftover, new_tier_config.price, new_tier_config.period)?;
+            (0, bonus_time)
+        } else {
+            (new_tier_config.price - credit, 0)
+        };
+        let platform_fee = platform.calculate_fee(amount_charged)?;
+
+        // Transfer funds to platform
+        let route = PaymentRoute::new(
+            new_tier_config.payment_mint,
+            subscriber.to_account_info(),
+            ctx.accounts.subscriber_token_account.as_ref(),
+            ctx.accounts.token_program.as_ref(),
+        )?;
+        if amount_charged > 0 {
+            let platform_recipient = route.recipient(
+                &platform.to_account_info(),
+                ctx.accounts.platform_token_account.as_ref(),
+            )?;
+            route.pay(&[(platform_recipient, amount_charged)])?;
+        }
+
+        // Add the creators' share to this epoch's revenue pool
+        accrue_subscription_revenue(
+            platform,
+            revenue_epoch,
+            ctx.accounts.token_revenue_pool.as_deref_mut(),
+            new_tier_config.payment_mint,
+            current_time,
+            amount_charged,
+            platform_fee,
+        )?;
+
+        let term = new_tier_config
+            .period
+            .checked_add(bonus_time)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        let expiration_time = current_time
+            .checked_add(term)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        // Update subscription
+        let previous_tier = subscription.tier;
+        subscription.tier = new_tier;
+        subscription.start_time = current_time;
+        subscription.expiration_time = expiration_time;
+        subscription.active = true;
+        subscription.paid_price = fees::checked_add(credit, amount_charged)?;
+        subscription.paid_period = term;
+
+        // Emit event
+        emit!(SubscriptionTierChanged {
+            subscriber: subscriber.key(),
+            previous_tier,
+            tier: new_tier,
+            price: amount_charged,
+            credit,
+            platform_fee,
+            expiration: expiration_time,
+            payment_mint: new_tier_config.payment_mint,
+            timestamp: current_time,
+        });
+
+        Ok(())
+    }
+
+    pub fn cancel_subscription(ctx: Context<CancelSubscription>) -> Result<()> {
+        let subscription = &mut ctx.accounts.subscription;
+
+        // Check if subscription is still running
+        require!(subscription.active, ErrorCode::SubscriptionNotActive);
+
+        // Cancellation is immediate and the unused term is not refunded
+        subscription.active = false;
+
+        // Emit event
+        emit!(SubscriptionCancelled {
+            subscriber: subscription.subscriber,
+            tier: subscription.tier,
+            expiration: subscription.expiration_time,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    pub fn access_content_via_subscription(
+        ctx: Context<AccessContentViaSubscription>,
+    ) -> Result<()> {
+        let content = &ctx.accounts.content;
+        let subscription = &ctx.accounts.subscription;
+        let access_receipt = &mut ctx.accounts.access_receipt;
+        let epoch_access = &mut ctx.accounts.epoch_access;
+        let revenue_epoch = &mut ctx.accounts.revenue_epoch;
+        let creator_epoch_stats = &mut ctx.accounts.creator_epoch_stats;
+
+        ctx.accounts
+            .platform
+            .require_not_paused(pause::PAUSE_SUBSCRIPTION_ACCESS)?;
+
+        // Check if content is active and offered to subscribers
+        require!(content.active, ErrorCode::ContentNotActive);
+        require!(
+            content.subscription_tier > 0,
+            ErrorCode::ContentNotInSubscription
+        );
+
+        // Check if subscription is active, unexpired and high enough
+        let current_time = Clock::get()?.unix_timestamp;
+        require!(subscription.active, ErrorCode::SubscriptionNotActive);
+        require!(
+            current_time < subscription.expiration_time,
+            ErrorCode::SubscriptionExpired
+        );
+        require!(
+            subscription.tier >= content.subscription_tier,
+            ErrorCode::SubscriptionTierTooLow
+        );
+
+        // Count each subscriber once per creator and epoch towards the creator's revenue
+        // share, however many of the creator's items they open; creators accessing their
+        // own content are not counted
+        let epoch = RevenueEpoch::index_at(current_time);
+        let first_access_this_epoch = epoch_access.subscriber == Pubkey::default();
+        if first_access_this_epoch {
+            epoch_access.subscriber = subscription.subscriber;
+            epoch_access.creator = content.creator;
+            epoch_access.epoch = epoch;
+        }
+        revenue_epoch.epoch = epoch;
+        creator_epoch_stats.creator = content.creator;
+        creator_epoch_stats.epoch = epoch;
+        if first_access_this_epoch && subscription.subscriber != content.creator {
+            revenue_epoch.total_accesses = fees::checked_add(revenue_epoch.total_accesses, 1)?;
+            creator_epoch_stats.access_count =
+                fees::checked_add(creator_epoch_stats.access_count, 1)?;
+        }
+
+        // Record access receipt
+        if access_receipt.access_count == 0 {
+            access_receipt.subscriber = subscription.subscriber;
+            access_receipt.content = content.key();
+            access_receipt.first_accessed_at = current_time;
+        }
+        access_receipt.tier = subscription.tier;
+        access_receipt.access_count = fees::checked_add(access_receipt.access_count, 1)?;
+        access_receipt.last_accessed_at = current_time;
+
+        // Emit event
+        emit!(ContentAccessedViaSubscription {
+            content_id: content.id.clone(),
+            content: content.key(),
+            creator: content.creator,
+            subscriber: subscription.subscriber,
+            tier: subscription.tier,
+            access_count: access_receipt.access_count,
+            expiration: subscription.expiration_time,
+            timestamp: current_time,
+        });
+
+        Ok(())
+    }
+
+    pub fn claim_subscription_revenue(
+        ctx: Context<ClaimSubscriptionRevenue>,
+        epoch: u64,
+    ) -> Result<()> {
+        let creator = &ctx.accounts.creator;
+        let platform = &mut ctx.accounts.platform;
+        let revenue_epoch = &mut ctx.accounts.revenue_epoch;
+        let creator_epoch_stats = &mut ctx.accounts.creator_epoch_stats;
+
+        platform.require_not_paused(pause::PAUSE_CLAIM_SUBSCRIPTION_REVENUE)?;
+
+        // Check if the epoch has closed, is still open for claims and has not been claimed yet
+        let current_time = Clock::get()?.unix_timestamp;
+        let current_epoch = RevenueEpoch::index_at(current_time);
+        require!(epoch < current_epoch, ErrorCode::RevenueEpochNotClosed);
+        require!(
+            fees::claim_window_open(epoch, current_epoch),
+            ErrorCode::ClaimWindowClosed
+        );
+        require!(!creator_epoch_stats.claimed, ErrorCode::RevenueAlreadyClaimed);
+
+        // Calculate the creator's pro-rata share of the pool
+        let amount = fees::pro_rata_share(
+            revenue_epoch.total_revenue,
+            creator_epoch_stats.access_count,
+            revenue_epoch.total_accesses,
+        )?;
+
+        // The platform PDA is owned by this program, so lamports can be moved directly
+        if amount > 0 {
+            **platform.to_account_info().try_borrow_mut_lamports()? -= amount;
+            **creator.to_account_info().try_borrow_mut_lamports()? += amount;
+        }
+
+        // Update pool accounting
+        creator_epoch_stats.claimed = true;
+        creator_epoch_stats.amount_claimed = amount;
+        revenue_epoch.total_claimed = fees::checked_add(revenue_epoch.total_claimed, amount)?;
+        revenue_epoch.claimed_accesses = fees::checked_add(
+            revenue_epoch.claimed_accesses,
+            creator_epoch_stats.access_count,
+        )?;
+        platform.subscription_pool_balance = platform
+            .subscription_pool_balance
+            .checked_sub(amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        // Emit event
+        emit!(SubscriptionRevenueClaimed {
+            creator: creator.key(),
+            epoch,
+            access_count: creator_epoch_stats.access_count,
+            total_accesses: revenue_epoch.total_accesses,
+            amount,
+            timestamp: current_time,
+        });
+
+        Ok(())
+    }
+
+    pub fn claim_earnings(ctx: Context<ClaimEarnings>) -> Result<()> {
+        let creator = &ctx.accounts.creator;
+        let creator_vault = &mut ctx.accounts.creator_vault;
+
+        ctx.accounts.platform.require_not_paused(pause::PAUSE_CLAIM_EARNINGS)?;
+
+        // Everything earned and not yet claimed
+        let amount = creator_vault.unclaimed()?;
+        require!(amount > 0, ErrorCode::NoEarningsToClaim);
+
+        // The vault PDA is owned by this program, so lamports can be moved directly
+        **creator_vault.to_account_info().try_borrow_mut_lamports()? -= amount;
+        **creator.to_account_info().try_borrow_mut_lamports()? += amount;
+
+        creator_vault.total_claimed = fees::checked_add(creator_vault.total_claimed, amount)?;
+
+        // Emit event
+        emit!(EarningsClaimed {
+            creator: creator.key(),
+            amount,
+            total_earned: creator_vault.total_earned,
+            total_claimed: creator_vault.total_claimed,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    pub fn claim_token_subscription_revenue(
+        ctx: Context<ClaimTokenSubscriptionRevenue>,
+        epoch: u64,
+    ) -> Result<()> {
+        let creator = &ctx.accounts.creator;
+        let platform = &mut ctx.accounts.platform;
+        let revenue_epoch = &ctx.accounts.revenue_epoch;
+        let creator_epoch_stats = &ctx.accounts.creator_epoch_stats;
+        let token_revenue_pool = &mut ctx.accounts.token_revenue_pool;
+        let token_revenue_claim = &mut ctx.accounts.token_revenue_claim;
+        let mint = ctx.accounts.payment_mint.key();
+
+        platform.require_not_paused(pause::PAUSE_CLAIM_SUBSCRIPTION_REVENUE)?;
+
+        // Check if the epoch has closed and is still open for claims; the claim account can
+        // only be created once
+        let current_time = Clock::get()?.unix_timestamp;
+        let current_epoch = RevenueEpoch::index_at(current_time);
+        require!(epoch < current_epoch, ErrorCode::RevenueEpochNotClosed);
+        require!(
+            fees::claim_window_open(epoch, current_epoch),
+            ErrorCode::ClaimWindowClosed
+        );
+
+        // Calculate the creator's pro-rata share of the token pool
+        let amount = fees::pro_rata_share(
+            token_revenue_pool.total_revenue,
+            creator_epoch_stats.access_count,
+            revenue_epoch.total_accesses,
+        )?;
+
+        // The platform PDA signs for its token account
+        if amount > 0 {
+            let bump = [ctx.bumps.platform];
+            let signer_seeds: &[&[&[u8]]] = &[&[b"platform", &bump]];
+            token::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.platform_token_account.to_account_info(),
+                        to: ctx.accounts.creator_token_account.to_account_info(),
+                        authority: platform.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                amount,
+            )?;
+        }
+
+        // Update pool accounting
+        token_revenue_claim.creator = creator.key();
+        token_revenue_claim.epoch = epoch;
+        token_revenue_claim.mint = mint;
+        token_revenue_claim.amount = amount;
+        token_revenue_pool.total_claimed =
+            fees::checked_add(token_revenue_pool.total_claimed, amount)?;
+        token_revenue_pool.claimed_accesses = fees::checked_add(
+            token_revenue_pool.claimed_accesses,
+            creator_epoch_stats.access_count,
+        )?;
+        let accepted_mint = platform.accepted_mint_mut(mint)?;
+        accepted_mint.subscription_pool_balance = accepted_mint
+            .subscription_pool_balance
+            .checked_sub(amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        // Emit event
+        emit!(TokenSubscriptionRevenueClaimed {
+            creator: creator.key(),
+            epoch,
+            mint,
+            access_count: creator_epoch_stats.access_count,
+            total_accesses: revenue_epoch.total_accesses,
+            amount,
+            timestamp: current_time,
+        });
+
+        Ok(())
+    }
+
+    pub fn sweep_subscription_revenue(
+        ctx: Context<SweepSubscriptionRevenue>,
+        epoch: u64,
+    ) -> Result<()> {
+        let platform = &mut ctx.accounts.platform;
+        let revenue_epoch = &mut ctx.accounts.revenue_epoch;
+
+        platform.require_not_paused(pause::PAUSE_CLAIM_SUBSCRIPTION_REVENUE)?;
+
+        // Only a closed epoch's unclaimable revenue and dust can be returned, or everything
+        // left unclaimed once the claim window has passed
+        let current_time = Clock::get()?.unix_timestamp;
+        let current_epoch = RevenueEpoch::index_at(current_time);
+        require!(epoch < current_epoch, ErrorCode::RevenueEpochNotClosed);
+        let amount = fees::unclaimable_revenue(
+            revenue_epoch.total_revenue,
+            fees::checked_add(revenue_epoch.total_claimed, revenue_epoch.total_swept)?,
+            revenue_epoch.total_accesses,
+            revenue_epoch.claimed_accesses,
+            fees::claim_window_open(epoch, current_epoch),
+        )?;
+        require!(amount > 0, ErrorCode::NoRevenueToSweep);
+
+        // The lamports already sit in the platform account; release them for withdrawal
+        revenue_epoch.total_swept = fees::checked_add(revenue_epoch.total_swept, amount)?;
+        platform.subscription_pool_balance = platform
+            .subscription_pool_balance
+            .checked_sub(amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        // Emit event
+        emit!(SubscriptionRevenueSwept {
+            epoch,
+            mint: None,
+            amount,
+            timestamp: current_time,
+        });
+
+        Ok(())
+    }
+
+    pub fn sweep_token_subscription_revenue(
+        ctx: Context<SweepTokenSubscriptionRevenue>,
+        epoch: u64,
+    ) -> Result<()> {
+        let platform = &mut ctx.accounts.platform;
+        let revenue_epoch = &ctx.accounts.revenue_epoch;
+        let token_revenue_pool = &mut ctx.accounts.token_revenue_pool;
+        let mint = ctx.accounts.payment_mint.key();
+
+        platform.require_not_paused(pause::PAUSE_CLAIM_SUBSCRIPTION_REVENUE)?;
+
+        // Only a closed epoch's unclaimable revenue and dust can be returned, or everything
+        // left unclaimed once the claim window has passed
+        let current_time = Clock::get()?.unix_timestamp;
+        let current_epoch = RevenueEpoch::index_at(current_time);
+        require!(epoch < current_epoch, ErrorCode::RevenueEpochNotClosed);
+        let amount = fees::unclaimable_revenue(
+            token_revenue_pool.total_revenue,
+            fees::checked_add(token_revenue_pool.total_claimed, token_revenue_pool.total_swept)?,
+            revenue_epoch.total_accesses,
+            token_revenue_pool.claimed_accesses,
+            fees::claim_window_open(epoch, current_epoch),
+        )?;
+        require!(amount > 0, ErrorCode::NoRevenueToSweep);
+
+        // The tokens already sit in the platform's token account; release them for withdrawal
+        token_revenue_pool.total_swept = fees::checked_add(token_revenue_pool.total_swept, amount)?;
+        let accepted_mint = platform.accepted_mint_mut(mint)?;
+        accepted_mint.subscription_pool_balance = accepted_mint
+            .subscription_pool_balance
+            .checked_sub(amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        // Emit event
+        emit!(SubscriptionRevenueSwept {
+            epoch,
+            mint: Some(mint),
+            amount,
+            timestamp: current_time,
+        });
+
+        Ok(())
+    }
+
+    pub fn claim_token_earnings(ctx: Context<ClaimTokenEarnings>) -> Result<()> {
+        let creator = &ctx.accounts.creator;
+        let creator_vault = &mut ctx.accounts.creator_vault;
+        let mint = ctx.accounts.payment_mint.key();
+
+        ctx.accounts.platform.require_not_paused(pause::PAUSE_CLAIM_EARNINGS)?;
+
+        // Everything earned in the mint and not yet claimed
+        let earnings = creator_vault.token_earnings_mut(mint)?;
+        let amount = earnings.unclaimed()?;
+        require!(amount > 0, ErrorCode::NoEarningsToClaim);
+        earnings.total_claimed = fees::checked_add(earnings.total_claimed, amount)?;
+        let total_earned = earnings.total_earned;
+        let total_claimed = earnings.total_claimed;
+
+        // The vault PDA signs for its token account
+        let creator_key = creator.key();
+        let bump = [ctx.bumps.creator_vault];
+        let signer_seeds: &[&[&[u8]]] = &[&[b"creator_vault", creator_key.as_ref(), &bump]];
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.vault_token_account.to_account_info(),
+                    to: ctx.accounts.creator_token_account.to_account_info(),
+                    authority: creator_vault.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            amount,
+        )?;
+
+        // Emit event
+        emit!(TokenEarningsClaimed {
+            creator: creator.key(),
+            mint,
+            amount,
+            total_earned,
+            total_claimed,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    pub fn verify_access(ctx: Context<VerifyAccess>) -> Result<AccessInfo> {
+        let content = &ctx.accounts.content;
+
+        // Check if content is active
+        require!(content.active, ErrorCode::ContentNotActive);
+
+        let current_time = Clock::get()?.unix_timestamp;
+        let mut denial = ErrorCode::NotPurchased;
+
+        // Owned copies and rentals grant access until they expire
+        let purchase = load_program_account::<Purchase>(&ctx.accounts.purchase)?;
+        let rental = load_program_account::<Purchase>(&ctx.accounts.rental)?;
+        for record in [purchase, rental].into_iter().flatten() {
+            if record.transferred_to.is_some() {
+                denial = ErrorCode::PurchaseTransferred;
+                continue;
+            }
+
+            // A copy minted as an NFT belongs to whoever holds the NFT
+            if let Some(nft_mint) = record.nft_mint {
+                let holds_nft = ctx.accounts.nft_token_account.as_ref().is_some_and(|account| {
+                    account.mint == nft_mint
+                        && account.owner == ctx.accounts.user.key()
+                        && account.amount > 0
+                });
+                if !holds_nft {
+                    denial = ErrorCode::NftNotHeld;
+                    continue;
+                }
+            }
+
+            let kind = match record.purchase_type {
+                PurchaseType::FullPurchase => AccessKind::Purchase,
+                PurchaseType::Rental => AccessKind::Rental,
+            };
+            match record.expiration {
+                Some(expiration) if current_time >= expiration => {
+                    denial = match kind {
+                        AccessKind::Rental => ErrorCode::RentalExpired,
+                        _ => ErrorCode::PurchaseExpired,
+                    };
+                }
+                expiration => return Ok(AccessInfo { kind, expiration }),
+            }
+        }
+
+        // Tier-gated content is also open to subscribers at or above its tier
+        if content.subscription_tier > 0 {
+            if let Some(subscription) =
+                load_program_account::<Subscription>(&ctx.accounts.subscription)?
+            {
+                if !subscription.active {
+                    denial = ErrorCode::SubscriptionNotActive;
+                } else if current_time >= subscription.expiration_time {
+                    denial = ErrorCode::SubscriptionExpired;
+                } else if subscription.tier < content.subscription_tier {
+                    denial = ErrorCode::SubscriptionTierTooLow;
+                } else {
+                    return Ok(AccessInfo {
+                        kind: AccessKind::Subscription,
+                        expiration: Some(subscription.expiration_time),
+                    });
+                }
+            }
+        }
+
+        Err(denial.into())
+    }
+
+    pub fn resell_content<'info>(
+        ctx: Context<'_, '_, '_, 'info, ResellContent<'info>>,
+        price: u64,
+    ) -> Result<()> {
+        let content = &ctx.accounts.content;
+        let seller = &ctx.accounts.seller;
+        let buyer = &ctx.accounts.buyer;
+        let creator_vault = &mut ctx.accounts.creator_vault;
+        let platform = &mut ctx.accounts.platform;
+        let seller_purchase = &mut ctx.accounts.seller_purchase;
+        let buyer_purchase = &mut ctx.accounts.buyer_purchase;
+
+        platform.require_not_paused(pause::PAUSE_RESALE)?;
+
+        // The wallet must not already own a copy
+        resale::check_purchase_reusable(buyer_purchase)?;
+
+        // Check if content is active
+        require!(content.active, ErrorCode::ContentNotActive);
+        
+        // Validate price
+        require!(price > 0, ErrorCode::InvalidPrice);
+
+        // Check if seller has unexpired resale rights
+        check_resale_rights(seller_purchase)?;
+
+        // An NFT-backed copy moves with its NFT, which only a listing can transfer
+        require!(seller_purchase.nft_mint.is_none(), ErrorCode::NftBackedPurchase);
+
+        // Calculate royalty, platform fee and seller share
+        let platform_fee_amount = platform.calculate_fee(price)?;
+        let split = fees::split_resale(price, content.royalty_percentage, platform_fee_amount)?;
+
+        // Transfer funds using helper function
+        let route = PaymentRoute::new(
+            content.payment_mint,
+            buyer.to_account_info(),
+            ctx.accounts.buyer_token_account.as_ref(),
+            ctx.accounts.token_program.as_ref(),
+        )?;
+        let payout = CreatorPayout::new(
+            content,
+            content.payment_mint,
+            route.recipient(
+                &creator_vault.to_account_info(),
+                ctx.accounts.creator_vault_token_account.as_ref(),
+            )?,
+            ctx.remaining_accounts,
+        );
+        let payments = split_payments(
+            &split,
+            &payout,
+            Some(route.recipient(
+                &seller.to_account_info(),
+                ctx.accounts.seller_token_account.as_ref(),
+            )?),
+            route.recipient(
+                &platform.to_account_info(),
+                ctx.accounts.platform_token_account.as_ref(),
+            )?,
+        )?;
+        route.pay(&payments)?;
+        creator_vault.record_earnings(payout.payment_mint, payout.vault_earnings(&payments))?;
+
+        // Update platform stats
+        platform.record_sale_volume(content.payment_mint, price)?;
+
+        // Move ownership to the buyer
+        resale::transfer_purchase(
+            seller_purchase,
+            &ctx.accounts.seller_listing.to_account_info(),
+            &seller.to_account_info(),
+            buyer_purchase,
+            buyer.key(),
+            price,
+            Clock::get()?.unix_timestamp,
+        )?;
+
+        // Emit event
+        emit!(ContentResold {
+            content_id: content.id.clone(),
+            seller: seller.key(),
+            buyer: buyer.key(),
+            creator: content.creator,
+            price,
+            royalty_amount: split.royalty,
+            platform_fee: split.platform_fee,
+            payment_mint: content.payment_mint,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    pub fn list_for_resale(ctx: Context<ListForResale>, price: u64) -> Result<()> {
+        let content = &ctx.accounts.content;
+        let seller = &ctx.accounts.seller;
+        let seller_purchase = &ctx.accounts.seller_purchase;
+        let listing = &mut ctx.accounts.listing;
+
+        ctx.accounts.platform.require_not_paused(pause::PAUSE_RESALE)?;
+
+        // Check if content is active
+        require!(content.active, ErrorCode::ContentNotActive);
+
+        // Validate price
+        require!(price > 0, ErrorCode::InvalidPrice);
+
+        // Check if seller has unexpired resale rights
+        check_resale_rights(seller_purchase)?;
+
+        // Record listing
+        listing.seller = seller.key();
+        listing.content = content.key();
+        listing.purchase = seller_purchase.key();
+        listing.price = price;
+        listing.created_at = Clock::get()?.unix_timestamp;
+        listing.payment_mint = content.payment_mint;
+        listing.acquired_at = seller_purchase.timestamp;
+
+        // Emit event
+        emit!(ListingCreated {
+            content_id: content.id.clone(),
+            listing: listing.key(),
+            seller: seller.key(),
+            price,
+            payment_mint: listing.payment_mint,
+            timestamp: listing.created_at,
+        });
+
+        Ok(())
+    }
+
+    pub fn buy_listing<'info>(
+        ctx: Context<'_, '_, '_, 'info, BuyListing<'info>>,
+        price: u64,
+    ) -> Result<()> {
+        let content = &ctx.accounts.content;
+        let buyer = &ctx.accounts.buyer;
+        let seller = &ctx.accounts.seller;
+        let creator_vault = &mut ctx.accounts.creator_vault;
+        let platform = &mut ctx.accounts.platform;
+        let listing = &ctx.accounts.listing;
+        let seller_purchase = &mut ctx.accounts.seller_purchase;
+        let buyer_purchase = &mut ctx.accounts.buyer_purchase;
+
+        platform.require_not_paused(pause::PAUSE_RESALE)?;
+
+        // The wallet must not already own a copy
+        resale::check_purchase_reusable(buyer_purchase)?;
+
+        // Check if content is active
+        require!(content.active, ErrorCode::ContentNotActive);
+
+        // Guard against the listing being repriced before this transaction lands
+        require!(price == listing.price, ErrorCode::ListingPriceMismatch);
+
+        // A listing is void once the creator switches the content to another payment mint
+        require!(
+            listing.payment_mint == content.payment_mint,
+            ErrorCode::PaymentMintMismatch
+        );
+
+        // Check if seller still has unexpired resale rights
+        check_resale_rights(seller_purchase)?;
+
+        // The listing must be for the copy the seller holds now, not one sold and bought back
+        resale::check_listing_current(listing, seller_purchase)?;
+
+        // Calculate royalty, platform fee and seller share
+        let platform_fee_amount = platform.calculate_fee(price)?;
+        let split = fees::split_resale(price, content.royalty_percentage, platform_fee_amount)?;
+
+        // Transfer funds using helper function
+        let route = PaymentRoute::new(
+            content.payment_mint,
+            buyer.to_account_info(),
+            ctx.accounts.buyer_token_account.as_ref(),
+            ctx.accounts.token_program.as_ref(),
+        )?;
+        let payout = CreatorPayout::new(
+            content,
+            content.payment_mint,
+            route.recipient(
+                &creator_vault.to_account_info(),
+                ctx.accounts.creator_vault_token_account.as_ref(),
+            )?,
+            ctx.remaining_accounts,
+        );
+        let payments = split_payments(
+            &split,
+            &payout,
+            Some(route.recipient(
+                &seller.to_account_info(),
+                ctx.accounts.seller_token_account.as_ref(),
+            )?),
+            route.recipient(
+                &platform.to_account_info(),
+                ctx.accounts.platform_token_account.as_ref(),
+            )?,
+        )?;
+        route.pay(&payments)?;
+        creator_vault.record_earnings(payout.payment_mint, payout.vault_earnings(&payments))?;
+
+        // Update platform stats
+        platform.record_sale_volume(content.payment_mint, price)?;
+
+        // An NFT-backed copy moves with its NFT, locked again in the buyer's account
+        if let Some(nft_mint) = seller_purchase.nft_mint {
+            let nft = PurchaseNft::new(
+                content.to_account_info(),
+                nft_mint,
+                ctx.accounts.nft_mint.as_ref(),
+                ctx.accounts.nft_master_edition.as_ref(),
+                ctx.accounts.token_program.as_ref(),
+                ctx.accounts.token_metadata_program.as_ref(),
+            )?;
+            let seller_nft_account = ctx
+                .accounts
+                .seller_nft_account
+                .as_ref()
+                .ok_or(ErrorCode::MissingNftAccounts)?;
+            let buyer_nft_account = ctx
+                .accounts
+                .buyer_nft_account
+                .as_ref()
+                .ok_or(ErrorCode::MissingNftAccounts)?;
+            require!(
+                seller_nft_account.mint == nft_mint
+                    && seller_nft_account.owner == seller.key()
+                    && seller_nft_account.amount == 1,
+                ErrorCode::NftNotHeld
+            );
+            require!(
+                buyer_nft_account.mint == nft_mint && buyer_nft_account.owner == buyer.key(),
+                ErrorCode::InvalidTokenAccount
+            );
+
+            let content_bump = [ctx.bumps.content];
+            let signer_seeds: &[&[&[u8]]] = &[&[b"content", content.id.as_bytes(), &content_bump]];
+            nft.transfer(
+                seller_nft_account.to_account_info(),
+                buyer_nft_account.to_account_info(),
+                signer_seeds,
+            )?;
+            nft.lock(buyer.to_account_info(), buyer_nft_account.to_account_info(), signer_seeds)?;
+        }
+
+        // Move ownership to the buyer
+        let current_time = Clock::get()?.unix_timestamp;
+        resale::transfer_purchase(
+            seller_purchase,
+            &listing.to_account_info(),
+            &seller.to_account_info(),
+            buyer_purchase,
+            buyer.key(),
+            price,
+            current_time,
+        )?;
+
+        // Emit event
+        emit!(ListingPurchased {
+            content_id: content.id.clone(),
+            listing: listing.key(),
+            seller: seller.key(),
+            buyer: buyer.key(),
+            creator: content.creator,
+            price,
+            royalty_amount: split.royalty,
+            platform_fee: split.platform_fee,
+            payment_mint: content.payment_mint,
+            timestamp: current_time,
+        });
+
+        Ok(())
+    }
+
+    pub fn cancel_listing(ctx: Context<CancelListing>) -> Result<()> {
+        let listing = &ctx.accounts.listing;
+
+        // Emit event
+        emit!(ListingCancelled {
+            listing: listing.key(),
+            seller: listing.seller,
+            content: listing.content,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    pub fn create_auction(
+        ctx: Context<CreateAuction>,
+        reserve_price: u64,
+        start_time: i64,
+        end_time: i64,
+        min_increment: u64,
+        extension_window: i64,
+    ) -> Result<()> {
+        let content = &mut ctx.accounts.content;
+        let auction = &mut ctx.accounts.auction;
+
+        ctx.accounts.platform.require_not_paused(pause::PAUSE_AUCTION)?;
+
+        // Check if content is active and not sold out
+        require!(content.active, ErrorCode::ContentNotActive);
+        require!(content.has_unreserved_supply(), ErrorCode::ContentSoldOut);
+
+        // Bid escrow only holds lamports
+        require!(content.payment_mint.is_none(), ErrorCode::TokenEscrowUnsupported);
+
+        // Input validation
+        let current_time = Clock::get()?.unix_timestamp;
+        require!(reserve_price > 0, ErrorCode::InvalidPrice);
+        require!(min_increment > 0, ErrorCode::InvalidBidIncrement);
+        require!(
+            start_time < end_time && end_time > current_time && extension_window >= 0,
+            ErrorCode::InvalidAuctionTimes
+        );
+
+        // Record auction
+        auction.content = content.key();
+        auction.creator = content.creator;
+        auction.reserve_price = reserve_price;
+        auction.start_time = start_time;
+        auction.end_time = end_time;
+        auction.min_increment = min_increment;
+        auction.extension_window = extension_window;
+        auction.highest_bidder = None;
+        auction.highest_bid = 0;
+        auction.bid_count = 0;
+
+        // Hold back one edition so the winner can always be settled
+        content.reserved_editions = fees::checked_add(content.reserved_editions, 1)?;
+
+        // Emit event
+        emit!(AuctionCreated {
+            content_id: content.id.clone(),
+            auction: auction.key(),
+            creator: content.creator,
+            reserve_price,
+            start_time,
+            end_time,
+            min_increment,
+            timestamp: current_time,
+        });
+
+        Ok(())
+    }
+
+    pub fn place_bid(ctx: Context<PlaceBid>, amount: u64) -> Result<()> {
+        let bidder = &ctx.accounts.bidder;
+        let previous_bidder = &ctx.accounts.previous_bidder;
+        let auction = &mut ctx.accounts.auction;
+
+        ctx.accounts.platform.require_not_paused(pause::PAUSE_AUCTION)?;
+
+        // Check if auction is running
+        let current_time = Clock::get()?.unix_timestamp;
+        require!(current_time >= auction.start_time, ErrorCode::AuctionNotStarted);
+        require!(current_time < auction.end_time, ErrorCode::AuctionEnded);
+
+        // A winner who already owns the content could never receive their purchase record
+        if let Some(bidder_purchase) =
+            load_program_account::<Purchase>(&ctx.accounts.bidder_purchase)?
+        {
+            resale::check_purchase_reusable(&bidder_purchase)?;
+        }
+
+        // Check if bid meets the reserve or beats the current bid by the minimum increment
+        let minimum_bid = match auction.highest_bidder {
+            Some(_) => fees::checked_add(auction.highest_bid, auction.min_increment)?,
+            None => auction.reserve_price,
+        };
+        require!(amount >= minimum_bid, ErrorCode::BidTooLow);
+
+        // Escrow the bid in the auction account
+        invoke(
+            &system_instruction::transfer(bidder.key, &auction.key(), amount),
+            &[bidder.to_account_info(), auction.to_account_info()],
+        )?;
+
+        // Refund the previous high bidder from escrow
+        if let Some(previous) = auction.highest_bidder {
+            require_keys_eq!(previous_bidder.key(), previous, ErrorCode::NotAuthorized);
+            let auction_info = auction.to_account_info();
+            let remaining = auction_info
+                .lamports()
+                .checked_sub(auction.highest_bid)
+                .ok_or(ErrorCode::InsufficientFunds)?;
+            **auction_info.try_borrow_mut_lamports()? = remaining;
+            **previous_bidder.try_borrow_mut_lamports()? += auction.highest_bid;
+        }
+
+        // Extend the auction when a bid lands in its final moments
+        let time_left = auction.end_time - current_time;
+        if time_left < auction.extension_window {
+            auction.end_time = current_time
+                .checked_add(auction.extension_window)
+                .ok_or(ErrorCode::ArithmeticOverflow)?;
+        }
+
+        // Record bid
+        auction.highest_bidder = Some(bidder.key());
+        auction.highest_bid = amount;
+        auction.bid_count = fees::checked_add(auction.bid_count, 1)?;
+
+        // Emit event
+        emit!(BidPlaced {
+            auction: auction.key(),
+            content: auction.content,
+            bidder: bidder.key(),
+            amount,
+            end_time: auction.end_time,
+            timestamp: current_time,
+        });
+
+        Ok(())
+    }
+
+    pub fn settle_auction<'info>(
+        ctx: Context<'_, '_, '_, 'info, SettleAuction<'info>>,
+    ) -> Result<()> {
+        let content = &mut ctx.accounts.content;
+        let winner = &ctx.accounts.winner;
+        let creator = &ctx.accounts.creator;
+        let creator_vault = &mut ctx.accounts.creator_vault;
+        let platform = &mut ctx.accounts.platform;
+        let auction = &ctx.accounts.auction;
+        let winner_purchase = &mut ctx.accounts.winner_purchase;
+
+        platform.require_not_paused(pause::PAUSE_AUCTION)?;
+
+        // Check if auction has ended with a winning bid
+        let current_time = Clock::get()?.unix_timestamp;
+        require!(current_time >= auction.end_time, ErrorCode::AuctionNotEnded);
+        require!(
+            auction.highest_bidder == Some(winner.key()),
+            ErrorCode::AuctionHasNoBids
+        );
+
+        // A winner who got a copy some other way since bidding cannot take a second one:
+        // refund the bid and return the reserved edition to general sale
+        if winner_purchase.buyer != Pubkey::default() && winner_purchase.transferred_to.is_none() {
+            let refund = auction.highest_bid;
+            transfer_funds_from_escrow(
+                &auction.to_account_info(),
+                &[(winner.to_account_info(), refund)],
+            )?;
+            content.release_reserved_edition();
+
+            // Emit event
+            emit!(AuctionVoided {
+                content_id: content.id.clone(),
+                auction: auction.key(),
+                winner: winner.key(),
+                refund,
+                timestamp: current_time,
+            });
+
+            return Ok(());
+        }
+
+        // Calculate platform fee and creator share
+        let price = auction.highest_bid;
+        let platform_fee = platform.calculate_fee(price)?;
+        let split = fees::split_primary_sale(price, platform_fee)?;
+
+        // Pay out of the escrow held by the auction account, which is always in lamports
+        let payout = CreatorPayout::new(content, None, creator_vault.to_account_info(), ctx.remaining_accounts);
+        let payments = split_payments(&split, &payout, None, platform.to_account_info())?;
+        transfer_funds_from_escrow(&auction.to_account_info(), &payments)?;
+        creator_vault.record_earnings(payout.payment_mint, payout.vault_earnings(&payments))?;
+
+        // Update content stats, turning the reserved edition into a sale
+        content.release_reserved_edition();
+        let edition_number = content.record_sale()?;
+
+        // Update platform stats
+        platform.total_sales_volume = fees::checked_add(platform.total_sales_volume, price)?;
+
+        // Record purchase for the winner
+        winner_purchase.buyer = winner.key();
+        winner_purchase.content = content.key();
+        winner_purchase.price = price;
+        winner_purchase.timestamp = current_time;
+        winner_purchase.resale_rights = true;
+        winner_purchase.purchase_type = PurchaseType::FullPurchase;
+        winner_purchase.expiration = None;
+        winner_purchase.acquired_from = None;
+        winner_purchase.transferred_to = None;
+        winner_purchase.refund_escrow = None;
+        winner_purchase.edition_number = Some(edition_number);
+        winner_purchase.nft_mint = None;
+        winner_purchase.pass_mint = None;
+
+        // Emit event
+        emit!(AuctionSettled {
+            content_id: content.id.clone(),
+            auction: auction.key(),
+            winner: winner.key(),
+            creator: creator.key(),
+            price,
+            platform_fee: split.platform_fee,
+            edition_number,
+            timestamp: current_time,
+        });
+
+        Ok(())
+    }
+
+    pub fn cancel_auction(ctx: Context<CancelAuction>) -> Result<()> {
+        let auction = &ctx.accounts.auction;
+
+        // Only auctions nobody has bid on can be cancelled
+        require!(auction.highest_bidder.is_none(), ErrorCode::AuctionHasBids);
+
+        // Return the reserved edition to general sale
+        ctx.accounts.content.release_reserved_edition();
+
+        // Emit event
+        emit!(AuctionCancelled {
+            auction: auction.key(),
+            content: auction.content,
+            creator: auction.creator,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    pub fn make_offer(ctx: Context<MakeOffer>, amount: u64, expires_at: i64) -> Result<()> {
+        let content = &ctx.accounts.content;
+        let bidder = &ctx.accounts.bidder;
+        let offer = &mut ctx.accounts.offer;
+
+        ctx.accounts.platform.require_not_paused(pause::PAUSE_RESALE)?;
+
+        // Check if content is active
+        require!(content.active, ErrorCode::ContentNotActive);
+
+        // Offer escrow only holds lamports
+        require!(content.payment_mint.is_none(), ErrorCode::TokenEscrowUnsupported);
+
+        // Input validation
+        let current_time = Clock::get()?.unix_timestamp;
+        require!(amount > 0, ErrorCode::InvalidPrice);
+        require!(expires_at > current_time, ErrorCode::InvalidOfferExpiration);
+
+        // Escrow the offered amount, plus a deposit for the rent of the bidder's purchase
+        // record, which the accepting seller creates; it is returned if unused
+        let escrow = fees::checked_add(amount, purchase_record_rent()?)?;
+        invoke(
+            &system_instruction::transfer(bidder.key, &offer.key(), escrow),
+            &[bidder.to_account_info(), offer.to_account_info()],
+        )?;
+
+        // Record offer
+        offer.bidder = bidder.key();
+        offer.content = content.key();
+        offer.amount = amount;
+        offer.created_at = current_time;
+        offer.expires_at = expires_at;
+
+        // Emit event
+        emit!(OfferMade {
+            content_id: content.id.clone(),
+            offer: offer.key(),
+            bidder: bidder.key(),
+            amount,
+            expires_at,
+            timestamp: current_time,
+        });
+
+        Ok(())
+    }
+
+    pub fn cancel_offer(ctx: Context<CancelOffer>) -> Result<()> {
+        let offer = &ctx.accounts.offer;
+
+        // Escrow and rent are returned to the bidder when the offer account closes
+        emit!(OfferCancelled {
+            offer: offer.key(),
+            bidder: offer.bidder,
+            content: offer.content,
+            amount: offer.amount,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    pub fn refund_expired_offer(ctx: Context<RefundExpiredOffer>) -> Result<()> {
+        let offer = &ctx.accounts.offer;
+
+        // Anyone can refund an offer once it has expired
+        let current_time = Clock::get()?.unix_timestamp;
+        require!(current_time >= offer.expires_at, ErrorCode::OfferNotExpired);
+
+        // Escrow and rent are returned to the bidder when the offer account closes
+        emit!(OfferRefunded {
+            offer: offer.key(),
+            bidder: offer.bidder,
+            content: offer.content,
+            amount: offer.amount,
+            timestamp: current_time,
+        });
+
+        Ok(())
+    }
+
+    pub fn accept_offer<'info>(ctx: Context<'_, '_, '_, 'info, AcceptOffer<'info>>) -> Result<()> {
+        let content = &ctx.accounts.content;
+        let seller = &ctx.accounts.seller;
+        let bidder = &ctx.accounts.bidder;
+        let creator_vault = &mut ctx.accounts.creator_vault;
+        let platform = &mut ctx.accounts.platform;
+        let offer = &ctx.accounts.offer;
+        let seller_purchase = &mut ctx.accounts.seller_purchase;
+        let buyer_purchase = &mut ctx.accounts.buyer_purchase;
+
+        platform.require_not_paused(pause::PAUSE_RESALE)?;
+
+        // The wallet must not already own a copy
+        resale::check_purchase_reusable(buyer_purchase)?;
+
+        // Check if content is active
+        require!(content.active, ErrorCode::ContentNotActive);
+
+        // Check if offer is still open
+        let current_time = Clock::get()?.unix_timestamp;
+        require!(current_time < offer.expires_at, ErrorCode::OfferExpired);
+
+        // Check if seller has unexpired resale rights
+        check_resale_rights(seller_purchase)?;
+
+        // An NFT-backed copy moves with its NFT, which only a listing can transfer
+        require!(seller_purchase.nft_mint.is_none(), ErrorCode::NftBackedPurchase);
+
+        // Calculate royalty, platform fee and seller share
+        let price = offer.amount;
+        let platform_fee_amount = platform.calculate_fee(price)?;
+        let split = fees::split_resale(price, content.royalty_percentage, platform_fee_amount)?;
+
+        // Pay out of the escrow held by the offer account, which is always in lamports
+        let payout = CreatorPayout::new(content, None, creator_vault.to_account_info(), ctx.remaining_accounts);
+        let payments = split_payments(
+            &split,
+            &payout,
+            Some(seller.to_account_info()),
+            platform.to_account_info(),
+        )?;
+        transfer_funds_from_escrow(&offer.to_account_info(), &payments)?;
+        creator_vault.record_earnings(payout.payment_mint, payout.vault_earnings(&payments))?;
+
+        // The bidder's deposit repays the seller for creating the bidder's purchase record
+        if buyer_purchase.buyer == Pubkey::default() {
+            transfer_funds_from_escrow(
+                &offer.to_account_info(),
+                &[(seller.to_account_info(), purchase_record_rent()?)],
+            )?;
+        }
+
+        // Update platform stats
+        platform.total_sales_volume = fees::checked_add(platform.total_sales_volume, price)?;
+
+        // Move ownership to the bidder
+        resale::transfer_purchase(
+            seller_purchase,
+            &ctx.accounts.seller_listing.to_account_info(),
+            &seller.to_account_info(),
+            buyer_purchase,
+            bidder.key(),
+            price,
+            current_time,
+        )?;
+
+        // Emit event
+        emit!(OfferAccepted {
+            content_id: content.id.clone(),
+            offer: offer.key(),
+            seller: seller.key(),
+            bidder: bidder.key(),
+            creator: content.creator,
+            price,
+            royalty_amount: split.royalty,
+            platform_fee: split.platform_fee,
+            timestamp: current_time,
+        });
+
+        Ok(())
+    }
+
+    pub fn update_platform_fee(
+        ctx: Context<UpdatePlatformFee>,
+        platform_fee_bps: u16,
+        min_platform_fee: Option<u64>,
+        max_platform_fee: Option<u64>,
+    ) -> Result<()> {
+        // Input validation
+        validate_platform_fee(platform_fee_bps, min_platform_fee, max_platform_fee)?;
+
+        let platform = &mut ctx.accounts.platform;
+        let previous_fee_bps = platform.platform_fee_bps;
+        platform.platform_fee_bps = platform_fee_bps;
+        platform.min_platform_fee = min_platform_fee;
+        platform.max_platform_fee = max_platform_fee;
+
+        // Emit event
+        emit!(PlatformFeeUpdated {
+            authority: ctx.accounts.authority.key(),
+            previous_fee_bps,
+            platform_fee_bps,
+            min_platform_fee,
+            max_platform_fee,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    pub fn set_accepted_mint(ctx: Context<SetAcceptedMint>, active: bool) -> Result<()> {
+        let platform = &mut ctx.accounts.platform;
+        let mint = ctx.accounts.payment_mint.key();
+
+        // Retiring a mint keeps its entry so its subscription pool stays claimable
+        match platform.accepted_mint_mut(mint) {
+            Ok(accepted) => accepted.active = active,
+            Err(_) => {
+                require!(active, ErrorCode::MintNotAccepted);
+                require!(
+                    platform.accepted_mints.len() < Platform::MAX_ACCEPTED_MINTS,
+                    ErrorCode::TooManyAcceptedMints
+                );
+                platform.accepted_mints.push(AcceptedMint {
+                    mint,
+                    active,
+                    subscription_pool_balance: 0,
+                });
+            }
+        }
+
+        // Emit event
+        emit!(AcceptedMintUpdated {
+            authority: ctx.accounts.authority.key(),
+            mint,
+            active,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    pub fn set_price_oracle(
+        ctx: Context<SetPriceOracle>,
+        price_oracle: Option<PriceOracle>,
+    ) -> Result<()> {
+        // The new feed must be a live price account of the new oracle program
+        if let Some(config) = &price_oracle {
+            load_price_feed(config, ctx.accounts.price_feed.as_ref())?;
+        }
+
+        let platform = &mut ctx.accounts.platform;
+        platform.price_oracle = price_oracle;
+
+        // Emit event
+        emit!(PriceOracleUpdated {
+            authority: ctx.accounts.authority.key(),
+            price_oracle,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    pub fn propose_authority(ctx: Context<ProposeAuthority>, new_authority: Pubkey) -> Result<()> {
+        let platform = &mut ctx.accounts.platform;
+
+        // Input validation
+        require!(
+            new_authority != platform.authority,
+            ErrorCode::InvalidPendingAuthority
+        );
+
+        platform.pending_authority = Some(new_authority);
+
+        // Emit event
+        emit!(AuthorityTransferProposed {
+            authority: platform.authority,
+            pending_authority: new_authority,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+        let platform = &mut ctx.accounts.platform;
+        let previous_authority = platform.authority;
+
+        platform.authority = ctx.accounts.pending_authority.key();
+        platform.pending_authority = None;
+
+        // Emit event
+        emit!(AuthorityTransferAccepted {
+            previous_authority,
+            authority: platform.authority,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    pub fn cancel_authority_transfer(ctx: Context<CancelAuthorityTransfer>) -> Result<()> {
+        let platform = &mut ctx.accounts.platform;
+
+        // Check if there is a transfer to cancel
+        let pending_authority = platform
+            .pending_authority
+            .take()
+            .ok_or(ErrorCode::NoPendingAuthority)?;
+
+        // Emit event
+        emit!(AuthorityTransferCancelled {
+            authority: platform.authority,
+            pending_authority,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    pub fn set_pause(ctx: Context<SetPause>, paused: u32) -> Result<()> {
+        let platform = &mut ctx.accounts.platform;
+        let previous_paused = platform.paused;
+        platform.paused = paused;
+
+        // Emit event
+        emit!(PlatformPauseUpdated {
+            authority: ctx.accounts.authority.key(),
+            previous_paused,
+            paused,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    pub fn withdraw_platform_fees(ctx: Context<WithdrawPlatformFees>, amount: u64) -> Result<()> {
+        // Input validation
+        require!(amount > 0, ErrorCode::InvalidWithdrawalAmount);
+
+        let platform_info = ctx.accounts.platform.to_account_info();
+        let destination = &ctx.accounts.destination;
+
+        // Only lamports above the rent-exempt minimum that are not owed to creators
+        // through the subscription pool can leave the platform account
+        let rent_exempt_minimum = Rent::get()?.minimum_balance(platform_info.data_len());
+        let available = platform_info
+            .lamports()
+            .saturating_sub(rent_exempt_minimum)
+            .saturating_sub(ctx.accounts.platform.subscription_pool_balance);
+        require!(amount <= available, ErrorCode::InsufficientPlatformBalance);
+
+        // The platform PDA is owned by this program, so lamports can be moved directly
+        **platform_info.try_borrow_mut_lamports()? -= amount;
+        **destination.try_borrow_mut_lamports()? += amount;
+
+        // Update platform stats
+        let platform = &mut ctx.accounts.platform;
+        platform.total_withdrawn = fees::checked_add(platform.total_withdrawn, amount)?;
+
+        // Emit event
+        emit!(PlatformFeesWithdrawn {
+            authority: ctx.accounts.authority.key(),
+            destination: destination.key(),
+            amount,
+            total_withdrawn: platform.total_withdrawn,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    pub fn withdraw_platform_token_fees(
+        ctx: Context<WithdrawPlatformTokenFees>,
+        amount: u64,
+    ) -> Result<()> {
+        // Input validation
+        require!(amount > 0, ErrorCode::InvalidWithdrawalAmount);
+
+        let platform = &ctx.accounts.platform;
+        let mint = ctx.accounts.payment_mint.key();
+
+        // Tokens owed to creators through the subscription pool stay behind
+        let pool_balance = platform
+            .accepted_mints
+            .iter()
+            .find(|accepted| accepted.mint == mint)
+            .map_or(0, |accepted| accepted.subscription_pool_balance);
+        let available = ctx
+            .accounts
+            .platform_token_account
+            .amount
+            .saturating_sub(pool_balance);
+        require!(amount <= available, ErrorCode::InsufficientPlatformBalance);
+
+        // The platform PDA signs for its token account
+        let bump = [ctx.bumps.platform];
+        let signer_seeds: &[&[&[u8]]] = &[&[b"platform", &bump]];
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.platform_token_account.to_account_info(),
+                    to: ctx.accounts.destination.to_account_info(),
+                    authority: platform.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            amount,
+        )?;
+
+        // Emit event
+        emit!(PlatformTokenFeesWithdrawn {
+            authority: ctx.accounts.authority.key(),
+            destination: ctx.accounts.destination.key(),
+            mint,
+            amount,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
+
+// Rental pass metadata: the expiry is stored as a unix timestamp under this key
+const RENTAL_PASS_EXPIRY_KEY: &str = "expires_at";
+const RENTAL_PASS_SYMBOL: &str = "RENTAL";
+
+// The content PDA is permanent delegate and close authority of every pass, so an
+// expired pass can be burned and its mint closed without the renter
+const RENTAL_PASS_EXTENSIONS: [ExtensionType; 4] = [
+    ExtensionType::NonTransferable,
+    ExtensionType::PermanentDelegate,
+    ExtensionType::MintCloseAuthority,
+    ExtensionType::MetadataPointer,
+];
+
+fn rental_pass_metadata(content: &Content, mint: Pubkey, expiration: i64) -> TokenMetadata {
+    let name = format!("Rental: {}", content.id);
+    TokenMetadata {
+        mint,
+        name: nft::truncate(&name, nft::MAX_NAME_LEN).to_string(),
+        symbol: RENTAL_PASS_SYMBOL.to_string(),
+        uri: format!("https://arweave.net/{}", content.arweave_id),
+        additional_metadata: vec![(RENTAL_PASS_EXPIRY_KEY.to_string(), expiration.to_string())],
+        ..Default::default()
+    }
+}
+
+// Helper function to validate platform fee settings
+fn validate_platform_fee(
+    platform_fee_bps: u16,
+    min_platform_fee: Option<u64>,
+    max_platform_fee: Option<u64>,
+) -> Result<()> {
+    require!(
+        platform_fee_bps <= MAX_BASIS_POINTS,
+        ErrorCode::InvalidFeeAmount
+    );
+
+    if let (Some(min_fee), Some(max_fee)) = (min_platform_fee, max_platform_fee) {
+        require!(min_fee <= max_fee, ErrorCode::InvalidFeeBounds);
+    }
+
+    Ok(())
+}
+
+// Helper function to add the non-fee part of a subscription payment to the epoch pool
+fn accrue_subscription_revenue(
+    platform: &mut Platform,
+    revenue_epoch: &mut RevenueEpoch,
+    token_revenue_pool: Option<&mut TokenRevenuePool>,
+    payment_mint: Option<Pubkey>,
+    timestamp: i64,
+    amount: u64,
+    platform_fee: u64,
+) -> Result<()> {
+    let creator_share = fees::split_primary_sale(amount, platform_fee)?.seller_amount;
+    let epoch = RevenueEpoch::index_at(timestamp);
+    revenue_epoch.epoch = epoch;
+
+    // Token revenue is pooled per mint and shared out by the same access counts
+    match payment_mint {
+        None => {
+            revenue_epoch.total_revenue =
+                fees::checked_add(revenue_epoch.total_revenue, creator_share)?;
+            platform.subscription_pool_balance =
+                fees::checked_add(platform.subscription_pool_balance, creator_share)?;
+        }
+        Some(mint) => {
+            let token_revenue_pool = token_revenue_pool.ok_or(ErrorCode::MissingTokenAccounts)?;
+            token_revenue_pool.epoch = epoch;
+            token_revenue_pool.mint = mint;
+            token_revenue_pool.total_revenue =
+                fees::checked_add(token_revenue_pool.total_revenue, creator_share)?;
+
+            let accepted_mint = platform.accepted_mint_mut(mint)?;
+            accepted_mint.subscription_pool_balance =
+                fees::checked_add(accepted_mint.subscription_pool_balance, creator_share)?;
+        }
+    }
+
+    Ok(())
+}
+
+// Helper function to read an optional program account, returning None if it was never created
+fn load_program_account<T: AccountDeserialize + Owner>(info: &AccountInfo) -> Result<Option<T>> {
+    if info.owner != &T::owner() || info.data_is_empty() {
+        return Ok(None);
+    }
+
+    let data = info.try_borrow_data()?;
+    Ok(Some(T::try_deserialize(&mut &data[..])?))
+}
+
+// Helper function to check a purchase can be resold
+fn check_resale_rights(purchase: &Purchase) -> Result<()> {
+    require!(
+        purchase.transferred_to.is_none(),
+        ErrorCode::PurchaseTransferred
+    );
+    require!(purchase.resale_rights, ErrorCode::NoResaleRights);
+    require!(purchase.refund_escrow.is_none(), ErrorCode::PurchaseInEscrow);
+
+    // Check if purchase is not expired (for rentals)
+    if let Some(expiration) = purchase.expiration {
+        let current_time = Clock::get()?.unix_timestamp;
+        require!(current_time < expiration, ErrorCode::PurchaseExpired);
+    }
+
+    Ok(())
+}
+
+// Helper function to refund an escrowed purchase: closing the purchase account returns
+// the held payment and the account rent to the buyer
+fn refund_purchase<'info>(
+    purchase: &mut Account<'info, Purchase>,
+    buyer: &AccountInfo<'info>,
+    content: &mut Account<Content>,
+    platform: &mut Account<Platform>,
+) -> Result<()> {
+    require!(purchase.refund_escrow.is_some(), ErrorCode::PurchaseNotInEscrow);
+
+    // A refunded copy no longer counts towards the content's supply
+    if purchase.purchase_type == PurchaseType::FullPurchase {
+        content.reverse_sale()?;
+    }
+    platform.total_sales_volume = platform
+        .total_sales_volume
+        .checked_sub(purchase.price)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+    purchase.close(buyer.clone())
+}
+
+// Helper function to convert a USD price to lamports at the platform's price feed
+fn usd_price_in_lamports(
+    usd_cents: u64,
+    price_oracle: &PriceOracle,
+    price_feed: Option<&UncheckedAccount>,
+    now: i64,
+) -> Result<u64> {
+    let feed = load_price_feed(price_oracle, price_feed)?.checked(
+        now,
+        oracle::MAX_PRICE_AGE,
+        oracle::MAX_CONFIDENCE_BPS,
+    )?;
+    oracle::usd_cents_to_lamports(usd_cents, &feed)
+}
+
+// Helper function to read the oracle's SOL/USD price account; any other feed of the
+// oracle prices a different pair
+fn load_price_feed(
+    price_oracle: &PriceOracle,
+    price_feed: Option<&UncheckedAccount>,
+) -> Result<oracle::PriceFeed> {
+    let price_feed = price_feed.ok_or(ErrorCode::InvalidPriceFeed)?;
+    require_keys_eq!(price_feed.key(), price_oracle.sol_usd_feed, ErrorCode::InvalidPriceFeed);
+
+    let data = price_feed.try_borrow_data()?;
+    oracle::load_price_account(&price_oracle.program_id, price_feed.owner, &data)
+}
+
+// Helper function to price one rental period, converting USD pricing if set
+fn rental_price_at(
+    content: &Content,
+    platform: &Platform,
+    price_feed: Option<&UncheckedAccount>,
+    now: i64,
+) -> Result<u64> {
+    match &content.usd_pricing {
+        Some(usd_pricing) => usd_price_in_lamports(
+            usd_pricing.rental_price_usd_cents,
+            platform.price_oracle()?,
+            price_feed,
+            now,
+        ),
+        None => Ok(content.rental_price),
+    }
+}
+
+// How a payment moves: lamports out of the payer's wallet, or SPL tokens out of the
+// payer's token account when a payment mint is set
+struct PaymentRoute<'a, 'info> {
+    payment_mint: Option<Pubkey>,
+    payer: AccountInfo<'info>,
+    payer_token_account: Option<&'a Account<'info, TokenAccount>>,
+    token_program: Option<&'a Program<'info, Token>>,
+}
+
+impl<'a, 'info> PaymentRoute<'a, 'info> {
+    fn new(
+        payment_mint: Option<Pubkey>,
+        payer: AccountInfo<'info>,
+        payer_token_account: Option<&'a Account<'info, TokenAccount>>,
+        token_program: Option<&'a Program<'info, Token>>,
+    ) -> Result<Self> {
+        if let Some(mint) = payment_mint {
+            let payer_token_account = payer_token_account.ok_or(ErrorCode::MissingTokenAccounts)?;
+            require!(token_program.is_some(), ErrorCode::MissingTokenAccounts);
+            require!(
+                payer_token_account.mint == mint && payer_token_account.owner == payer.key(),
+                ErrorCode::InvalidTokenAccount
+            );
+        }
+
+        Ok(Self {
+            payment_mint,
+            payer,
+            payer_token_account,
+            token_program,
+        })
+    }
+
+    // Account that receives a payment owed to `owner`: the owner itself for lamports,
+    // or its associated token account for the payment mint
+    fn recipient(
+        &self,
+        owner: &AccountInfo<'info>,
+        token_account: Option<&Account<'info, TokenAccount>>,
+    ) -> Result<AccountInfo<'info>> {
+        match self.payment_mint {
+            None => Ok(owner.clone()),
+            Some(mint) => {
+                let token_account = token_account.ok_or(ErrorCode::MissingTokenAccounts)?;
+                require_keys_eq!(
+                    token_account.key(),
+                    get_associated_token_address(owner.key, &mint),
+                    ErrorCode::InvalidTokenAccount
+                );
+                Ok(token_account.to_account_info())
+            }
+        }
+    }
+
+    fn pay(&self, payments: &[(AccountInfo<'info>, u64)]) -> Result<()> {
+        if self.payment_mint.is_none() {
+            return transfer_funds(&self.payer, payments);
+        }
+        let payer_token_account = self.payer_token_account.ok_or(ErrorCode::MissingTokenAccounts)?;
+        let token_program = self.token_program.ok_or(ErrorCode::MissingTokenAccounts)?;
+
+        for (recipient, amount) in payments {
+            if *amount == 0 {
+                continue;
+            }
+
+            token::transfer(
+                CpiContext::new(
+                    token_program.to_account_info(),
+                    Transfer {
+                        from: payer_token_account.to_account_info(),
+                        to: recipient.clone(),
+                        authority: self.payer.clone(),
+                    },
+                ),
+                *amount,
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+// Accounts that move a purchase NFT. Its holder's token account stays frozen with the
+// content as delegate, so the NFT cannot change hands outside the program and skip the
+// royalty and platform fee
+struct PurchaseNft<'info> {
+    content: AccountInfo<'info>,
+    mint: AccountInfo<'info>,
+    master_edition: AccountInfo<'info>,
+    token_program: AccountInfo<'info>,
+    token_metadata_program: AccountInfo<'info>,
+}
+
+impl<'info> PurchaseNft<'info> {
+    fn new(
+        content: AccountInfo<'info>,
+        nft_mint: Pubkey,
+        mint: Option<&Account<'info, Mint>>,
+        master_edition: Option<&UncheckedAccount<'info>>,
+        token_program: Option<&Program<'info, Token>>,
+        token_metadata_program: Option<&Program<'info, Metadata>>,
+    ) -> Result<Self> {
+        let mint = mint.ok_or(ErrorCode::MissingNftAccounts)?;
+        require_keys_eq!(mint.key(), nft_mint, ErrorCode::InvalidTokenAccount);
+
+        Ok(Self {
+            content,
+            mint: mint.to_account_info(),
+            master_edition: master_edition.ok_or(ErrorCode::MissingNftAccounts)?.to_account_info(),
+            token_program: token_program.ok_or(ErrorCode::MissingNftAccounts)?.to_account_info(),
+            token_metadata_program: token_metadata_program
+                .ok_or(ErrorCode::MissingNftAccounts)?
+                .to_account_info(),
+        })
+    }
+
+    // Approves the content as delegate of the holder's token account, then freezes it
+    fn lock(
+        &self,
+        holder: AccountInfo<'info>,
+        token_account: AccountInfo<'info>,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<()> {
+        token::approve(
+            CpiContext::new(
+                self.token_program.clone(),
+                Approve {
+                    to: token_account.clone(),
+                    delegate: self.content.clone(),
+                    authority: holder,
+                },
+            ),
+            1,
+        )?;
+        metadata::freeze_delegated_account(CpiContext::new_with_signer(
+            self.token_metadata_program.clone(),
+            FreezeDelegatedAccount {
+                metadata: self.token_metadata_program.clone(),
+                delegate: self.content.clone(),
+                token_account,
+                edition: self.master_edition.clone(),
+                mint: self.mint.clone(),
+                token_program: self.token_program.clone(),
+            },
+            signer_seeds,
+        ))
+    }
+
+    // Thaws the holder's token account and moves the NFT out as its delegate
+    fn transfer(
+        &self,
+        from: AccountInfo<'info>,
+        to: AccountInfo<'info>,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<()> {
+        metadata::thaw_delegated_account(CpiContext::new_with_signer(
+            self.token_metadata_program.clone(),
+            ThawDelegatedAccount {
+                metadata: self.token_metadata_program.clone(),
+                delegate: self.content.clone(),
+                token_account: from.clone(),
+                edition: self.master_edition.clone(),
+                mint: self.mint.clone(),
+                token_program: self.token_program.clone(),
+            },
+            signer_seeds,
+        ))?;
+        token::transfer(
+            CpiContext::new_with_signer(
+                self.token_program.clone(),
+                Transfer {
+                    from,
+                    to,
+                    authority: self.content.clone(),
+                },
+                signer_seeds,
+            ),
+            1,
+        )
+    }
+}
+
+// Recipients of the creator's share of a payment: the creator alone, or the content's
+// revenue split table paid through the instruction's remaining accounts
+struct CreatorPayout<'a, 'info> {
+    creator: Pubkey,
+    payment_mint: Option<Pubkey>,
+    creator_vault: AccountInfo<'info>,
+    revenue_splits: Vec<RevenueSplit>,
+    remaining_accounts: &'a [AccountInfo<'info>],
+}
+
+impl<'a, 'info> CreatorPayout<'a, 'info> {
+    fn new(
+        content: &Content,
+        payment_mint: Option<Pubkey>,
+        creator_vault: AccountInfo<'info>,
+        remaining_accounts: &'a [AccountInfo<'info>],
+    ) -> Self {
+        Self {
+            creator: content.creator,
+            payment_mint,
+            creator_vault,
+            revenue_splits: content.revenue_splits.clone(),
+            remaining_accounts,
+        }
+    }
+
+    fn payments(&self, amount: u64) -> Result<Vec<(AccountInfo<'info>, u64)>> {
+        if self.revenue_splits.is_empty() {
+            return Ok(vec![(self.creator_vault.clone(), amount)]);
+        }
+
+        // Split recipients (their token accounts for token payments) must be passed in the
+        // same order as the split table, with the creator's vault standing in for the
+        // creator's own entry
+        require!(
+            self.remaining_accounts.len() >= self.revenue_splits.len(),
+            ErrorCode::InvalidSplitRecipient
+        );
+        let shares = self
+            .revenue_splits
+            .iter()
+            .map(|split| split.share_bps)
+            .collect::<Vec<u16>>();
+        let amounts = fees::split_by_shares(amount, &shares)?;
+
+        self.revenue_splits
+            .iter()
+            .zip(self.remaining_accounts)
+            .zip(amounts)
+            .map(|((split, recipient), amount)| {
+                let expected = match self.payment_mint {
+                    _ if split.recipient == self.creator => self.creator_vault.key(),
+                    Some(mint) => get_associated_token_address(&split.recipient, &mint),
+                    None => split.recipient,
+                };
+                require_keys_eq!(recipient.key(), expected, ErrorCode::InvalidSplitRecipient);
+                require!(recipient.is_writable, ErrorCode::InvalidSplitRecipient);
+                Ok((recipient.clone(), amount))
+            })
+            .collect()
+    }
+
+    // Part of `payments` credited to the creator's vault, or to its token account for
+    // token payments
+    fn vault_earnings(&self, payments: &[(AccountInfo<'info>, u64)]) -> u64 {
+        payments
+            .iter()
+            .filter(|(recipient, _)| recipient.key() == self.creator_vault.key())
+            .map(|(_, amount)| *amount)
+            .sum()
+    }
+}
+
+// Helper function to list who receives each part of a payment split; without a
+// separate seller (primary sales and rentals) the creator's side receives the seller share
+fn split_payments<'info>(
+    split: &fees::PaymentSplit,
+    creator: &CreatorPayout<'_, 'info>,
+    seller: Option<AccountInfo<'info>>,
+    platform: AccountInfo<'info>,
+) -> Result<Vec<(AccountInfo<'info>, u64)>> {
+    let mut payments = match seller {
+        Some(seller) => {
+            let mut payments = creator.payments(split.royalty)?;
+            payments.push((seller, split.seller_amount));
+            payments
+        }
+        None => creator.payments(fees::checked_add(split.royalty, split.seller_amount)?)?,
+    };
+    payments.push((platform, split.platform_fee));
+
+    Ok(payments)
+}
+
+// Helper function to pay out a sale from a program-owned escrow account
+fn transfer_funds_from_escrow(
+    escrow: &AccountInfo,
+    payments: &[(AccountInfo, u64)],
+) -> Result<()> {
+    let mut total: u64 = 0;
+    for (_, amount) in payments {
+        total = fees::checked_add(total, *amount)?;
+    }
+    let remaining = escrow
+        .lamports()
+        .checked_sub(total)
+        .ok_or(ErrorCode::InsufficientFunds)?;
+
+    **escrow.try_borrow_mut_lamports()? = remaining;
+    for (recipient, amount) in payments {
+        **recipient.try_borrow_mut_lamports()? += *amount;
+    }
+
+    Ok(())
+}
+
+// Helper function to get the rent-exempt balance of a purchase record
+fn purchase_record_rent() -> Result<u64> {
+    Ok(Rent::get()?.minimum_balance(8 + Purchase::LEN))
+}
+
+// Helper function to transfer funds
+fn transfer_funds<'info>(
+    from: &AccountInfo<'info>,
+    payments: &[(AccountInfo<'info>, u64)],
+) -> Result<()> {
+    for (recipient, amount) in payments {
+        if *amount == 0 {
+            continue;
+        }
+
+        invoke(
+            &system_instruction::transfer(from.key, recipient.key, *amount),
+            &[from.clone(), recipient.clone()],
+        )?;
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
 pub struct Initialize<'info> {
     #[account(
-        init,
-        payer = authority,
-        space = 8 + Platform::LEN,
-        seeds = [b"platform"],
+        init,
+        payer = authority,
+        space = 8 + Platform::LEN,
+        seeds = [b"platform"],
+        bump
+    )]
+    pub platform: Account<'info, Platform>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(content_id: String)]
+pub struct CreateContent<'info> {
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + Content::LEN,
+        seeds = [b"content", content_id.as_bytes()],
+        bump
+    )]
+    pub content: Account<'info, Content>,
+    
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = 8 + CreatorVault::LEN,
+        seeds = [b"creator_vault", creator.key().as_ref()],
+        bump
+    )]
+    pub creator_vault: Account<'info, CreatorVault>,
+    
+    #[account(
+        mut,
+        seeds = [b"platform"],
+        bump
+    )]
+    pub platform: Account<'info, Platform>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateContent<'info> {
+    #[account(
+        mut,
+        has_one = creator @ ErrorCode::NotAuthorized,
+    )]
+    pub content: Account<'info, Content>,
+    
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    
+    #[account(
+        seeds = [b"platform"],
+        bump
+    )]
+    pub platform: Account<'info, Platform>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetDecliningPrice<'info> {
+    #[account(
+        mut,
+        has_one = creator @ ErrorCode::NotAuthorized,
+    )]
+    pub content: Account<'info, Content>,
+    
+    pub creator: Signer<'info>,
+    
+    #[account(
+        seeds = [b"platform"],
+        bump
+    )]
+    pub platform: Account<'info, Platform>,
+}
+
+#[derive(Accounts)]
+pub struct SetRefundWindow<'info> {
+    #[account(
+        mut,
+        has_one = creator @ ErrorCode::NotAuthorized,
+    )]
+    pub content: Account<'info, Content>,
+    
+    pub creator: Signer<'info>,
+    
+    #[account(
+        seeds = [b"platform"],
+        bump
+    )]
+    pub platform: Account<'info, Platform>,
+}
+
+#[derive(Accounts)]
+pub struct SetUsdPricing<'info> {
+    #[account(
+        mut,
+        has_one = creator @ ErrorCode::NotAuthorized,
+    )]
+    pub content: Account<'info, Content>,
+    
+    pub creator: Signer<'info>,
+    
+    #[account(
+        seeds = [b"platform"],
+        bump
+    )]
+    pub platform: Account<'info, Platform>,
+    
+    /// CHECK: SOL/USD price feed, checked against the platform's price oracle
+    pub price_feed: Option<UncheckedAccount<'info>>,
+}
+
+#[derive(Accounts)]
+pub struct SetPaymentMint<'info> {
+    #[account(
+        mut,
+        has_one = creator @ ErrorCode::NotAuthorized,
+    )]
+    pub content: Account<'info, Content>,
+    
+    pub creator: Signer<'info>,
+    
+    #[account(
+        seeds = [b"platform"],
+        bump
+    )]
+    pub platform: Account<'info, Platform>,
+}
+
+#[derive(Accounts)]
+pub struct SetRevenueSplits<'info> {
+    #[account(
+        mut,
+        has_one = creator @ ErrorCode::NotAuthorized,
+    )]
+    pub content: Account<'info, Content>,
+    
+    pub creator: Signer<'info>,
+    
+    #[account(
+        seeds = [b"platform"],
+        bump
+    )]
+    pub platform: Account<'info, Platform>,
+}
+
+#[derive(Accounts)]
+pub struct CreateNftCollection<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"content", content.id.as_bytes()],
+        bump,
+        has_one = creator @ ErrorCode::NotAuthorized,
+        constraint = content.nft_collection.is_none() @ ErrorCode::NftCollectionExists,
+    )]
+    pub content: Account<'info, Content>,
+    
+    #[account(
+        init,
+        payer = creator,
+        seeds = [b"collection_mint", content.key().as_ref()],
+        bump,
+        mint::decimals = 0,
+        mint::authority = content,
+        mint::freeze_authority = content,
+    )]
+    pub collection_mint: Account<'info, Mint>,
+    
+    #[account(
+        init,
+        payer = creator,
+        associated_token::mint = collection_mint,
+        associated_token::authority = content,
+    )]
+    pub collection_token_account: Account<'info, TokenAccount>,
+    
+    /// CHECK: Created and checked by the token metadata program
+    #[account(mut)]
+    pub collection_metadata: UncheckedAccount<'info>,
+    
+    /// CHECK: Created and checked by the token metadata program
+    #[account(mut)]
+    pub collection_master_edition: UncheckedAccount<'info>,
+    
+    #[account(
+        seeds = [b"platform"],
+        bump
+    )]
+    pub platform: Account<'info, Platform>,
+    
+    pub token_metadata_program: Program<'info, Metadata>,
+    
+    pub token_program: Program<'info, Token>,
+    
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    
+    pub system_program: Program<'info, System>,
+    
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+pub struct MintPurchaseNft<'info> {
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    
+    #[account(
+        seeds = [b"content", content.id.as_bytes()],
+        bump,
+    )]
+    pub content: Account<'info, Content>,
+    
+    #[account(
+        mut,
+        seeds = [b"purchase", buyer.key().as_ref(), content.key().as_ref()],
+        bump,
+        has_one = buyer @ ErrorCode::NotAuthorized,
+    )]
+    pub purchase: Account<'info, Purchase>,
+    
+    #[account(
+        init,
+        payer = buyer,
+        seeds = [b"purchase_nft", purchase.key().as_ref()],
+        bump,
+        mint::decimals = 0,
+        mint::authority = content,
+        mint::freeze_authority = content,
+    )]
+    pub nft_mint: Account<'info, Mint>,
+    
+    #[account(
+        init,
+        payer = buyer,
+        associated_token::mint = nft_mint,
+        associated_token::authority = buyer,
+    )]
+    pub buyer_nft_account: Account<'info, TokenAccount>,
+    
+    /// CHECK: Created and checked by the token metadata program
+    #[account(mut)]
+    pub nft_metadata: UncheckedAccount<'info>,
+    
+    /// CHECK: Created and checked by the token metadata program
+    #[account(mut)]
+    pub nft_master_edition: UncheckedAccount<'info>,
+    
+    #[account(
+        constraint = content.nft_collection == Some(collection_mint.key()) @ ErrorCode::NftsNotEnabled,
+    )]
+    pub collection_mint: Account<'info, Mint>,
+    
+    #[account(mut)]
+    pub collection_metadata: Account<'info, MetadataAccount>,
+    
+    /// CHECK: Checked by the token metadata program
+    pub collection_master_edition: UncheckedAccount<'info>,
+    
+    #[account(
+        seeds = [b"platform"],
+        bump
+    )]
+    pub platform: Account<'info, Platform>,
+    
+    pub token_metadata_program: Program<'info, Metadata>,
+    
+    pub token_program: Program<'info, Token>,
+    
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    
+    pub system_program: Program<'info, System>,
+    
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+pub struct PurchaseContent<'info> {
+    #[account(mut)]
+    pub content: Account<'info, Content>,
+    
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"creator_vault", content.creator.as_ref()],
+        bump,
+    )]
+    pub creator_vault: Account<'info, CreatorVault>,
+    
+    #[account(
+        mut,
+        seeds = [b"platform"],
+        bump
+    )]
+    pub platform: Account<'info, Platform>,
+    
+    // Reused when the wallet's earlier copy was transferred away
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        space = 8 + Purchase::LEN,
+        seeds = [b"purchase", buyer.key().as_ref(), content.key().as_ref()],
+        bump
+    )]
+    pub purchase: Account<'info, Purchase>,
+    
+    pub system_program: Program<'info, System>,
+    
+    // SPL token accounts, required when the content is priced in a token
+    #[account(mut)]
+    pub buyer_token_account: Option<Account<'info, TokenAccount>>,
+    
+    #[account(mut)]
+    pub creator_vault_token_account: Option<Account<'info, TokenAccount>>,
+    
+    #[account(mut)]
+    pub platform_token_account: Option<Account<'info, TokenAccount>>,
+    
+    pub token_program: Option<Program<'info, Token>>,
+    
+    /// CHECK: SOL/USD price feed, checked against the platform's price oracle
+    pub price_feed: Option<UncheckedAccount<'info>>,
+}
+
+#[derive(Accounts)]
+pub struct RequestRefund<'info> {
+    pub buyer: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"purchase", buyer.key().as_ref(), purchase.content.as_ref()],
+        bump,
+        has_one = buyer @ ErrorCode::NotAuthorized,
+    )]
+    pub purchase: Account<'info, Purchase>,
+    
+    #[account(
+        seeds = [b"platform"],
+        bump
+    )]
+    pub platform: Account<'info, Platform>,
+}
+
+#[derive(Accounts)]
+pub struct ApproveRefund<'info> {
+    pub creator: Signer<'info>,
+    
+    #[account(
+        mut,
+        has_one = creator @ ErrorCode::NotAuthorized,
+    )]
+    pub content: Account<'info, Content>,
+    
+    /// CHECK: Receives the refund, checked against the purchase
+    #[account(mut)]
+    pub buyer: AccountInfo<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"purchase", buyer.key().as_ref(), content.key().as_ref()],
+        bump,
+        has_one = buyer @ ErrorCode::NotAuthorized,
+        has_one = content @ ErrorCode::NotAuthorized,
+    )]
+    pub purchase: Account<'info, Purchase>,
+    
+    #[account(
+        mut,
+        seeds = [b"platform"],
+        bump
+    )]
+    pub platform: Account<'info, Platform>,
+}
+
+#[derive(Accounts)]
+pub struct RejectRefund<'info> {
+    pub creator: Signer<'info>,
+    
+    #[account(has_one = creator @ ErrorCode::NotAuthorized)]
+    pub content: Account<'info, Content>,
+    
+    #[account(
+        mut,
+        has_one = content @ ErrorCode::NotAuthorized,
+    )]
+    pub purchase: Account<'info, Purchase>,
+    
+    #[account(
+        seeds = [b"platform"],
+        bump
+    )]
+    pub platform: Account<'info, Platform>,
+}
+
+#[derive(Accounts)]
+pub struct EscalateRefund<'info> {
+    pub buyer: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"purchase", buyer.key().as_ref(), purchase.content.as_ref()],
+        bump,
+        has_one = buyer @ ErrorCode::NotAuthorized,
+    )]
+    pub purchase: Account<'info, Purchase>,
+    
+    #[account(
+        seeds = [b"platform"],
+        bump
+    )]
+    pub platform: Account<'info, Platform>,
+}
+
+#[derive(Accounts)]
+pub struct ResolveDispute<'info> {
+    #[account(
+        mut,
+        seeds = [b"platform"],
+        bump,
+        has_one = authority @ ErrorCode::NotAuthorized,
+    )]
+    pub platform: Account<'info, Platform>,
+    
+    pub authority: Signer<'info>,
+    
+    /// CHECK: Receives a refund, checked against the purchase
+    #[account(mut)]
+    pub buyer: AccountInfo<'info>,
+    
+    #[account(mut)]
+    pub content: Account<'info, Content>,
+    
+    #[account(
+        mut,
+        seeds = [b"purchase", buyer.key().as_ref(), content.key().as_ref()],
+        bump,
+        has_one = buyer @ ErrorCode::NotAuthorized,
+        has_one = content @ ErrorCode::NotAuthorized,
+    )]
+    pub purchase: Account<'info, Purchase>,
+}
+
+#[derive(Accounts)]
+pub struct ReleaseEscrow<'info> {
+    pub content: Account<'info, Content>,
+    
+    #[account(
+        mut,
+        has_one = content @ ErrorCode::NotAuthorized,
+    )]
+    pub purchase: Account<'info, Purchase>,
+    
+    #[account(
+        mut,
+        seeds = [b"creator_vault", content.creator.as_ref()],
+        bump,
+    )]
+    pub creator_vault: Account<'info, CreatorVault>,
+    
+    #[account(
+        mut,
+        seeds = [b"platform"],
+        bump
+    )]
+    pub platform: Account<'info, Platform>,
+}
+
+#[derive(Accounts)]
+pub struct RentContent<'info> {
+    #[account(mut)]
+    pub content: Account<'info, Content>,
+    
+    #[account(mut)]
+    pub renter: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"creator_vault", content.creator.as_ref()],
+        bump,
+    )]
+    pub creator_vault: Account<'info, CreatorVault>,
+    
+    #[account(
+        mut,
+        seeds = [b"platform"],
+        bump
+    )]
+    pub platform: Account<'info, Platform>,
+    
+    // Reused once a previous rental of the same content has expired
+    #[account(
+        init_if_needed,
+        payer = renter,
+        space = 8 + Purchase::LEN,
+        seeds = [b"rental", renter.key().as_ref(), content.key().as_ref()],
+        bump
+    )]
+    pub rental: Account<'info, Purchase>,
+    
+    pub system_program: Program<'info, System>,
+    
+    // SPL token accounts, required when the content is priced in a token
+    #[account(mut)]
+    pub renter_token_account: Option<Account<'info, TokenAccount>>,
+    
+    #[account(mut)]
+    pub creator_vault_token_account: Option<Account<'info, TokenAccount>>,
+    
+    #[account(mut)]
+    pub platform_token_account: Option<Account<'info, TokenAccount>>,
+    
+    pub token_program: Option<Program<'info, Token>>,
+    
+    /// CHECK: SOL/USD price feed, checked against the platform's price oracle
+    pub price_feed: Option<UncheckedAccount<'info>>,
+}
+
+#[derive(Accounts)]
+pub struct ExtendRental<'info> {
+    #[account(
+        seeds = [b"content", content.id.as_bytes()],
+        bump,
+    )]
+    pub content: Account<'info, Content>,
+    
+    #[account(mut)]
+    pub renter: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"creator_vault", content.creator.as_ref()],
+        bump,
+    )]
+    pub creator_vault: Account<'info, CreatorVault>,
+    
+    #[account(
+        mut,
+        seeds = [b"platform"],
+        bump
+    )]
+    pub platform: Account<'info, Platform>,
+    
+    #[account(
+        mut,
+        seeds = [b"rental", renter.key().as_ref(), content.key().as_ref()],
+        bump,
+    )]
+    pub rental: Account<'info, Purchase>,
+    
+    pub system_program: Program<'info, System>,
+    
+    // SPL token accounts, required when the content is priced in a token
+    #[account(mut)]
+    pub renter_token_account: Option<Account<'info, TokenAccount>>,
+    
+    #[account(mut)]
+    pub creator_vault_token_account: Option<Account<'info, TokenAccount>>,
+    
+    #[account(mut)]
+    pub platform_token_account: Option<Account<'info, TokenAccount>>,
+    
+    pub token_program: Option<Program<'info, Token>>,
+    
+    /// CHECK: SOL/USD price feed, checked against the platform's price oracle
+    pub price_feed: Option<UncheckedAccount<'info>>,
+    
+    /// CHECK: Rental pass mint, required when the rental has a pass; checked against the rental
+    #[account(mut)]
+    pub pass_mint: Option<UncheckedAccount<'info>>,
+    
+    pub token_2022_program: Option<Program<'info, Token2022>>,
+}
+
+#[derive(Accounts)]
+pub struct CloseRental<'info> {
+    pub caller: Signer<'info>,
+    
+    /// CHECK: Receives the rental account rent, checked against the rental
+    #[account(mut)]
+    pub renter: AccountInfo<'info>,
+    
+    #[account(
+        mut,
+        close = renter,
+        seeds = [b"rental", renter.key().as_ref(), rental.content.as_ref()],
+        bump,
+        constraint = rental.buyer == renter.key() @ ErrorCode::NotAuthorized,
+    )]
+    pub rental: Account<'info, Purchase>,
+}
+
+#[derive(Accounts)]
+pub struct IssueRentalPass<'info> {
+    #[account(mut)]
+    pub renter: Signer<'info>,
+    
+    #[account(
+        seeds = [b"content", content.id.as_bytes()],
+        bump,
+    )]
+    pub content: Account<'info, Content>,
+    
+    #[account(
+        mut,
+        seeds = [b"rental", renter.key().as_ref(), content.key().as_ref()],
+        bump,
+    )]
+    pub rental: Account<'info, Purchase>,
+    
+    /// CHECK: Created and initialized as a Token-2022 mint by this instruction
+    #[account(
+        mut,
+        seeds = [b"rental_pass", rental.key().as_ref()],
+        bump,
+    )]
+    pub pass_mint: UncheckedAccount<'info>,
+    
+    /// CHECK: Created by the associated token program
+    #[account(
+        mut,
+        address = get_associated_token_address_with_program_id(
+            &renter.key(),
+            &pass_mint.key(),
+            &token_2022::ID,
+        ) @ ErrorCode::InvalidTokenAccount,
+    )]
+    pub renter_pass_account: UncheckedAccount<'info>,
+    
+    #[account(
+        seeds = [b"platform"],
+        bump
+    )]
+    pub platform: Account<'info, Platform>,
+    
+    pub token_2022_program: Program<'info, Token2022>,
+    
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct BurnExpiredRental<'info> {
+    /// CHECK: Receives the pass mint and rental rent, checked against the rental
+    #[account(mut)]
+    pub renter: AccountInfo<'info>,
+    
+    #[account(
+        seeds = [b"content", content.id.as_bytes()],
+        bump,
+    )]
+    pub content: Account<'info, Content>,
+    
+    #[account(
+        mut,
+        close = renter,
+        seeds = [b"rental", renter.key().as_ref(), content.key().as_ref()],
+        bump,
+        constraint = rental.buyer == renter.key() @ ErrorCode::NotAuthorized,
+    )]
+    pub rental: Account<'info, Purchase>,
+    
+    /// CHECK: Closed through the Token-2022 program, checked against the rental
+    #[account(
+        mut,
+        constraint = rental.pass_mint == Some(pass_mint.key()) @ ErrorCode::NoRentalPass,
+    )]
+    pub pass_mint: UncheckedAccount<'info>,
+    
+    // Omitted if the renter already burned the pass and closed the account
+    #[account(
+        mut,
+        constraint = renter_pass_account.mint == pass_mint.key() @ ErrorCode::InvalidTokenAccount,
+        constraint = renter_pass_account.owner == renter.key() @ ErrorCode::InvalidTokenAccount,
+    )]
+    pub renter_pass_account: Option<InterfaceAccount<'info, token_interface::TokenAccount>>,
+    
+    pub token_2022_program: Program<'info, Token2022>,
+}
+
+#[derive(Accounts)]
+#[instruction(tier: u8)]
+pub struct CreateSubscriptionTier<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + SubscriptionTierConfig::LEN,
+        seeds = [b"subscription_tier", [tier].as_ref()],
+        bump
+    )]
+    pub tier_config: Account<'info, SubscriptionTierConfig>,
+    
+    #[account(
+        seeds = [b"platform"],
+        bump,
+        has_one = authority @ ErrorCode::NotAuthorized,
+    )]
+    pub platform: Account<'info, Platform>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateSubscriptionTier<'info> {
+    #[account(
+        mut,
+        seeds = [b"subscription_tier", [tier_config.tier].as_ref()],
+        bump
+    )]
+    pub tier_config: Account<'info, SubscriptionTierConfig>,
+    
+    #[account(
+        seeds = [b"platform"],
+        bump,
+        has_one = authority @ ErrorCode::NotAuthorized,
+    )]
+    pub platform: Account<'info, Platform>,
+    
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct RetireSubscriptionTier<'info> {
+    #[account(
+        mut,
+        seeds = [b"subscription_tier", [tier_config.tier].as_ref()],
+        bump
+    )]
+    pub tier_config: Account<'info, SubscriptionTierConfig>,
+    
+    #[account(
+        seeds = [b"platform"],
+        bump,
+        has_one = authority @ ErrorCode::NotAuthorized,
+    )]
+    pub platform: Account<'info, Platform>,
+    
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(tier: u8)]
+pub struct Subscribe<'info> {
+    #[account(mut)]
+    pub subscriber: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"platform"],
+        bump
+    )]
+    pub platform: Account<'info, Platform>,
+    
+    #[account(
+        seeds = [b"subscription_tier", [tier].as_ref()],
+        bump
+    )]
+    pub tier_config: Account<'info, SubscriptionTierConfig>,
+    
+    #[account(
+        init_if_needed,
+        payer = subscriber,
+        space = 8 + RevenueEpoch::LEN,
+        seeds = [b"revenue_epoch", RevenueEpoch::current()?.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub revenue_epoch: Account<'info, RevenueEpoch>,
+    
+    #[account(
+        init_if_needed,
+        payer = subscriber,
+        space = 8 + TokenRevenuePool::LEN,
+        seeds = [
+            b"token_revenue_pool",
+            RevenueEpoch::current()?.to_le_bytes().as_ref(),
+            tier_config.payment_mint_seed(),
+        ],
+        bump
+    )]
+    pub token_revenue_pool: Option<Account<'info, TokenRevenuePool>>,
+    
+    #[account(
+        init,
+        payer = subscriber,
+        space = 8 + Subscription::LEN,
+        seeds = [b"subscription", subscriber.key().as_ref()],
+        bump
+    )]
+    pub subscription: Account<'info, Subscription>,
+    
+    pub system_program: Program<'info, System>,
+    
+    // SPL token accounts, required when the tier is priced in a token
+    #[account(mut)]
+    pub subscriber_token_account: Option<Account<'info, TokenAccount>>,
+    
+    #[account(mut)]
+    pub platform_token_account: Option<Account<'info, TokenAccount>>,
+    
+    pub token_program: Option<Program<'info, Token>>,
+}
+
+#[derive(Accounts)]
+pub struct RenewSubscription<'info> {
+    #[account(mut)]
+    pub subscriber: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"platform"],
+        bump
+    )]
+    pub platform: Account<'info, Platform>,
+    
+    #[account(
+        seeds = [b"subscription_tier", [subscription.tier].as_ref()],
+        bump
+    )]
+    pub tier_config: Account<'info, SubscriptionTierConfig>,
+    
+    #[account(
+        init_if_needed,
+        payer = subscriber,
+        space = 8 + RevenueEpoch::LEN,
+        seeds = [b"revenue_epoch", RevenueEpoch::current()?.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub revenue_epoch: Account<'info, RevenueEpoch>,
+    
+    #[account(
+        init_if_needed,
+        payer = subscriber,
+        space = 8 + TokenRevenuePool::LEN,
+        seeds = [
+            b"token_revenue_pool",
+            RevenueEpoch::current()?.to_le_bytes().as_ref(),
+            tier_config.payment_mint_seed(),
+        ],
+        bump
+    )]
+    pub token_revenue_pool: Option<Account<'info, TokenRevenuePool>>,
+    
+    #[account(
+        mut,
+        seeds = [b"subscription", subscriber.key().as_ref()],
+        bump,
+        has_one = subscriber @ ErrorCode::NotAuthorized,
+    )]
+    pub subscription: Account<'info, Subscription>,
+    
+    pub system_program: Program<'info, System>,
+    
+    // SPL token accounts, required when the tier is priced in a token
+    #[account(mut)]
+    pub subscriber_token_account: Option<Account<'info, TokenAccount>>,
+    
+    #[account(mut)]
+    pub platform_token_account: Option<Account<'info, TokenAccount>>,
+    
+    pub token_program: Option<Program<'info, Token>>,
+}
+
+#[derive(Accounts)]
+#[instruction(new_tier: u8)]
+pub struct ChangeSubscriptionTier<'info> {
+    #[account(mut)]
+    pub subscriber: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"platform"],
+        bump
+    )]
+    pub platform: Account<'info, Platform>,
+    
+    #[account(
+        seeds = [b"subscription_tier", [subscription.tier].as_ref()],
+        bump
+    )]
+    pub current_tier_config: Account<'info, SubscriptionTierConfig>,
+    
+    #[account(
+        seeds = [b"subscription_tier", [new_tier].as_ref()],
+        bump
+    )]
+    pub new_tier_config: Account<'info, SubscriptionTierConfig>,
+    
+    #[account(
+        init_if_needed,
+        payer = subscriber,
+        space = 8 + RevenueEpoch::LEN,
+        seeds = [b"revenue_epoch", RevenueEpoch::current()?.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub revenue_epoch: Account<'info, RevenueEpoch>,
+    
+    #[account(
+        init_if_needed,
+        payer = subscriber,
+        space = 8 + TokenRevenuePool::LEN,
+        seeds = [
+            b"token_revenue_pool",
+            RevenueEpoch::current()?.to_le_bytes().as_ref(),
+            new_tier_config.payment_mint_seed(),
+        ],
+        bump
+    )]
+    pub token_revenue_pool: Option<Account<'info, TokenRevenuePool>>,
+    
+    #[account(
+        mut,
+        seeds = [b"subscription", subscriber.key().as_ref()],
+        bump,
+        has_one = subscriber @ ErrorCode::NotAuthorized,
+    )]
+    pub subscription: Account<'info, Subscription>,
+    
+    pub system_program: Program<'info, System>,
+    
+    // SPL token accounts, required when the tier is priced in a token
+    #[account(mut)]
+    pub subscriber_token_account: Option<Account<'info, TokenAccount>>,
+    
+    #[account(mut)]
+    pub platform_token_account: Option<Account<'info, TokenAccount>>,
+    
+    pub token_program: Option<Program<'info, Token>>,
+}
+
+#[derive(Accounts)]
+pub struct CancelSubscription<'info> {
+    pub subscriber: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"subscription", subscriber.key().as_ref()],
+        bump,
+        has_one = subscriber @ ErrorCode::NotAuthorized,
+    )]
+    pub subscription: Account<'info, Subscription>,
+}
+
+#[derive(Accounts)]
+pub struct AccessContentViaSubscription<'info> {
+    pub content: Account<'info, Content>,
+    
+    #[account(mut)]
+    pub subscriber: Signer<'info>,
+    
+    #[account(
+        seeds = [b"platform"],
+        bump
+    )]
+    pub platform: Account<'info, Platform>,
+    
+    #[account(
+        seeds = [b"subscription", subscriber.key().as_ref()],
+        bump,
+        has_one = subscriber @ ErrorCode::NotAuthorized,
+    )]
+    pub subscription: Account<'info, Subscription>,
+    
+    #[account(
+        init_if_needed,
+        payer = subscriber,
+        space = 8 + AccessReceipt::LEN,
+        seeds = [b"access", subscriber.key().as_ref(), content.key().as_ref()],
+        bump
+    )]
+    pub access_receipt: Account<'info, AccessReceipt>,
+    
+    #[account(
+        init_if_needed,
+        payer = subscriber,
+        space = 8 + EpochAccess::LEN,
+        seeds = [
+            b"epoch_access",
+            subscriber.key().as_ref(),
+            content.creator.as_ref(),
+            RevenueEpoch::current()?.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub epoch_access: Account<'info, EpochAccess>,
+    
+    #[account(
+        init_if_needed,
+        payer = subscriber,
+        space = 8 + RevenueEpoch::LEN,
+        seeds = [b"revenue_epoch", RevenueEpoch::current()?.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub revenue_epoch: Account<'info, RevenueEpoch>,
+    
+    #[account(
+        init_if_needed,
+        payer = subscriber,
+        space = 8 + CreatorEpochStats::LEN,
+        seeds = [
+            b"creator_epoch",
+            content.creator.as_ref(),
+            RevenueEpoch::current()?.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub creator_epoch_stats: Account<'info, CreatorEpochStats>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(epoch: u64)]
+pub struct ClaimSubscriptionRevenue<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"platform"],
+        bump
+    )]
+    pub platform: Account<'info, Platform>,
+    
+    #[account(
+        mut,
+        seeds = [b"revenue_epoch", epoch.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub revenue_epoch: Account<'info, RevenueEpoch>,
+    
+    #[account(
+        mut,
+        seeds = [b"creator_epoch", creator.key().as_ref(), epoch.to_le_bytes().as_ref()],
+        bump,
+        has_one = creator @ ErrorCode::NotAuthorized,
+    )]
+    pub creator_epoch_stats: Account<'info, CreatorEpochStats>,
+}
+
+#[derive(Accounts)]
+#[instruction(epoch: u64)]
+pub struct ClaimTokenSubscriptionRevenue<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"platform"],
+        bump
+    )]
+    pub platform: Account<'info, Platform>,
+    
+    #[account(
+        seeds = [b"revenue_epoch", epoch.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub revenue_epoch: Account<'info, RevenueEpoch>,
+    
+    #[account(
+        seeds = [b"creator_epoch", creator.key().as_ref(), epoch.to_le_bytes().as_ref()],
+        bump,
+        has_one = creator @ ErrorCode::NotAuthorized,
+    )]
+    pub creator_epoch_stats: Account<'info, CreatorEpochStats>,
+    
+    pub payment_mint: Account<'info, Mint>,
+    
+    #[account(
+        mut,
+        seeds = [b"token_revenue_pool", epoch.to_le_bytes().as_ref(), payment_mint.key().as_ref()],
+        bump
+    )]
+    pub token_revenue_pool: Account<'info, TokenRevenuePool>,
+    
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + TokenRevenueClaim::LEN,
+        seeds = [
+            b"token_revenue_claim",
+            creator.key().as_ref(),
+            epoch.to_le_bytes().as_ref(),
+            payment_mint.key().as_ref(),
+        ],
+        bump
+    )]
+    pub token_revenue_claim: Account<'info, TokenRevenueClaim>,
+    
+    #[account(
+        mut,
+        associated_token::mint = payment_mint,
+        associated_token::authority = platform,
+    )]
+    pub platform_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        token::mint = payment_mint,
+        token::authority = creator,
+    )]
+    pub creator_token_account: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(epoch: u64)]
+pub struct SweepSubscriptionRevenue<'info> {
+    #[account(
+        mut,
+        seeds = [b"platform"],
+        bump,
+        has_one = authority @ ErrorCode::NotAuthorized,
+    )]
+    pub platform: Account<'info, Platform>,
+    
+    pub authority: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"revenue_epoch", epoch.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub revenue_epoch: Account<'info, RevenueEpoch>,
+}
+
+#[derive(Accounts)]
+#[instruction(epoch: u64)]
+pub struct SweepTokenSubscriptionRevenue<'info> {
+    #[account(
+        mut,
+        seeds = [b"platform"],
+        bump,
+        has_one = authority @ ErrorCode::NotAuthorized,
+    )]
+    pub platform: Account<'info, Platform>,
+    
+    pub authority: Signer<'info>,
+    
+    #[account(
+        seeds = [b"revenue_epoch", epoch.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub revenue_epoch: Account<'info, RevenueEpoch>,
+    
+    pub payment_mint: Account<'info, Mint>,
+    
+    #[account(
+        mut,
+        seeds = [b"token_revenue_pool", epoch.to_le_bytes().as_ref(), payment_mint.key().as_ref()],
+        bump
+    )]
+    pub token_revenue_pool: Account<'info, TokenRevenuePool>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimTokenEarnings<'info> {
+    pub creator: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"creator_vault", creator.key().as_ref()],
+        bump,
+        has_one = creator @ ErrorCode::NotAuthorized,
+    )]
+    pub creator_vault: Account<'info, CreatorVault>,
+    
+    pub payment_mint: Account<'info, Mint>,
+    
+    #[account(
+        mut,
+        associated_token::mint = payment_mint,
+        associated_token::authority = creator_vault,
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        token::mint = payment_mint,
+        token::authority = creator,
+    )]
+    pub creator_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        seeds = [b"platform"],
+        bump
+    )]
+    pub platform: Account<'info, Platform>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimEarnings<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"creator_vault", creator.key().as_ref()],
+        bump,
+        has_one = creator @ ErrorCode::NotAuthorized,
+    )]
+    pub creator_vault: Account<'info, CreatorVault>,
+    
+    #[account(
+        seeds = [b"platform"],
+        bump
+    )]
+    pub platform: Account<'info, Platform>,
+}
+
+#[derive(Accounts)]
+pub struct VerifyAccess<'info> {
+    pub content: Account<'info, Content>,
+    
+    /// CHECK: Wallet whose access is being verified, it does not need to sign
+    pub user: UncheckedAccount<'info>,
+    
+    /// CHECK: User's purchase record, may not exist
+    #[account(
+        seeds = [b"purchase", user.key().as_ref(), content.key().as_ref()],
+        bump
+    )]
+    pub purchase: UncheckedAccount<'info>,
+    
+    /// CHECK: User's rental record, may not exist
+    #[account(
+        seeds = [b"rental", user.key().as_ref(), content.key().as_ref()],
+        bump
+    )]
+    pub rental: UncheckedAccount<'info>,
+    
+    /// CHECK: User's subscription, may not exist
+    #[account(
+        seeds = [b"subscription", user.key().as_ref()],
+        bump
+    )]
+    pub subscription: UncheckedAccount<'info>,
+    
+    // User's token account for the purchase NFT, when the copy was minted as one
+    pub nft_token_account: Option<Account<'info, TokenAccount>>,
+}
+
+#[derive(Accounts)]
+pub struct ResellContent<'info> {
+    #[account(mut)]
+    pub content: Account<'info, Content>,
+    
+    #[account(mut)]
+    pub seller: Signer<'info>,
+    
+    // The buyer is debited, so both parties sign the resale
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"creator_vault", content.creator.as_ref()],
+        bump,
+    )]
+    pub creator_vault: Account<'info, CreatorVault>,
+    
+    #[account(
+        mut,
+        seeds = [b"platform"],
+        bump
+    )]
+    pub platform: Account<'info, Platform>,
+    
+    #[account(
+        mut,
+        seeds = [b"purchase", seller.key().as_ref(), content.key().as_ref()],
+        bump,
+        constraint = seller_purchase.buyer == seller.key() @ ErrorCode::NotAuthorized,
+        constraint = seller_purchase.content == content.key() @ ErrorCode::NotAuthorized,
+    )]
+    pub seller_purchase: Account<'info, Purchase>,
+    
+    /// CHECK: Seller's listing of the copy, closed with the sale if it exists
+    #[account(
+        mut,
+        seeds = [b"listing", seller_purchase.key().as_ref()],
+        bump
+    )]
+    pub seller_listing: UncheckedAccount<'info>,
+    
+    // Reused when the wallet's earlier copy was transferred away
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        space = 8 + Purchase::LEN,
+        seeds = [b"purchase", buyer.key().as_ref(), content.key().as_ref()],
+        bump
+    )]
+    pub buyer_purchase: Account<'info, Purchase>,
+    
+    pub system_program: Program<'info, System>,
+    
+    // SPL token accounts, required when the content is priced in a token
+    #[account(mut)]
+    pub buyer_token_account: Option<Account<'info, TokenAccount>>,
+    
+    #[account(mut)]
+    pub creator_vault_token_account: Option<Account<'info, TokenAccount>>,
+    
+    #[account(mut)]
+    pub seller_token_account: Option<Account<'info, TokenAccount>>,
+    
+    #[account(mut)]
+    pub platform_token_account: Option<Account<'info, TokenAccount>>,
+    
+    pub token_program: Option<Program<'info, Token>>,
+}
+
+#[derive(Accounts)]
+pub struct SetAcceptedMint<'info> {
+    #[account(
+        mut,
+        seeds = [b"platform"],
+        bump,
+        has_one = authority @ ErrorCode::NotAuthorized,
+    )]
+    pub platform: Account<'info, Platform>,
+    
+    pub authority: Signer<'info>,
+    
+    pub payment_mint: Account<'info, Mint>,
+}
+
+#[derive(Accounts)]
+pub struct SetPriceOracle<'info> {
+    #[account(
+        mut,
+        seeds = [b"platform"],
+        bump,
+        has_one = authority @ ErrorCode::NotAuthorized,
+    )]
+    pub platform: Account<'info, Platform>,
+    
+    pub authority: Signer<'info>,
+    
+    /// CHECK: SOL/USD price feed being set, checked against the new price oracle
+    pub price_feed: Option<UncheckedAccount<'info>>,
+}
+
+#[derive(Accounts)]
+pub struct UpdatePlatformFee<'info> {
+    #[account(
+        mut,
+        seeds = [b"platform"],
+        bump,
+        has_one = authority @ ErrorCode::NotAuthorized,
+    )]
+    pub platform: Account<'info, Platform>,
+    
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ProposeAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [b"platform"],
+        bump,
+        has_one = authority @ ErrorCode::NotAuthorized,
+    )]
+    pub platform: Account<'info, Platform>,
+    
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CancelAuthorityTransfer<'info> {
+    #[account(
+        mut,
+        seeds = [b"platform"],
+        bump,
+        has_one = authority @ ErrorCode::NotAuthorized,
+    )]
+    pub platform: Account<'info, Platform>,
+    
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [b"platform"],
+        bump,
+        constraint = platform.pending_authority == Some(pending_authority.key()) @ ErrorCode::NotAuthorized,
+    )]
+    pub platform: Account<'info, Platform>,
+    
+    pub pending_authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetPause<'info> {
+    #[account(
+        mut,
+        seeds = [b"platform"],
+        bump,
+        has_one = authority @ ErrorCode::NotAuthorized,
+    )]
+    pub platform: Account<'info, Platform>,
+    
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawPlatformTokenFees<'info> {
+    #[account(
+        seeds = [b"platform"],
+        bump,
+        has_one = authority @ ErrorCode::NotAuthorized,
+    )]
+    pub platform: Account<'info, Platform>,
+    
+    pub authority: Signer<'info>,
+    
+    pub payment_mint: Account<'info, Mint>,
+    
+    #[account(
+        mut,
+        associated_token::mint = payment_mint,
+        associated_token::authority = platform,
+    )]
+    pub platform_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        token::mint = payment_mint,
+    )]
+    pub destination: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawPlatformFees<'info> {
+    #[account(
+        mut,
+        seeds = [b"platform"],
+        bump,
+        has_one = authority @ ErrorCode::NotAuthorized,
+    )]
+    pub platform: Account<'info, Platform>,
+    
+    pub authority: Signer<'info>,
+    
+    /// CHECK: Any account chosen by the platform authority to receive the fees
+    #[account(mut)]
+    pub destination: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ListForResale<'info> {
+    pub content: Account<'info, Content>,
+    
+    #[account(mut)]
+    pub seller: Signer<'info>,
+    
+    #[account(
+        seeds = [b"platform"],
+        bump
+    )]
+    pub platform: Account<'info, Platform>,
+    
+    #[account(
+        seeds = [b"purchase", seller.key().as_ref(), content.key().as_ref()],
+        bump,
+        constraint = seller_purchase.buyer == seller.key() @ ErrorCode::NotAuthorized,
+        constraint = seller_purchase.content == content.key() @ ErrorCode::NotAuthorized,
+    )]
+    pub seller_purchase: Account<'info, Purchase>,
+    
+    #[account(
+        init,
+        payer = seller,
+        space = 8 + Listing::LEN,
+        seeds = [b"listing", seller_purchase.key().as_ref()],
+        bump
+    )]
+    pub listing: Account<'info, Listing>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct BuyListing<'info> {
+    #[account(
+        seeds = [b"content", content.id.as_bytes()],
+        bump,
+    )]
+    pub content: Account<'info, Content>,
+    
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    
+    /// CHECK: Receives the sale proceeds, checked against the listing
+    #[account(mut)]
+    pub seller: AccountInfo<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"creator_vault", content.creator.as_ref()],
+        bump,
+    )]
+    pub creator_vault: Account<'info, CreatorVault>,
+    
+    #[account(
+        mut,
+        seeds = [b"platform"],
+        bump
+    )]
+    pub platform: Account<'info, Platform>,
+    
+    // Closed along with the sale, returning its rent to the seller
+    #[account(
+        mut,
+        seeds = [b"listing", seller_purchase.key().as_ref()],
+        bump,
+        has_one = seller @ ErrorCode::NotAuthorized,
+        has_one = content @ ErrorCode::NotAuthorized,
+    )]
+    pub listing: Account<'info, Listing>,
+    
+    #[account(
+        mut,
+        seeds = [b"purchase", seller.key().as_ref(), content.key().as_ref()],
+        bump,
+    )]
+    pub seller_purchase: Account<'info, Purchase>,
+    
+    // Reused when the wallet's earlier copy was transferred away
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        space = 8 + Purchase::LEN,
+        seeds = [b"purchase", buyer.key().as_ref(), content.key().as_ref()],
+        bump
+    )]
+    pub buyer_purchase: Account<'info, Purchase>,
+    
+    pub system_program: Program<'info, System>,
+    
+    // SPL token accounts, required when the content is priced in a token
+    #[account(mut)]
+    pub buyer_token_account: Option<Account<'info, TokenAccount>>,
+    
+    #[account(mut)]
+    pub creator_vault_token_account: Option<Account<'info, TokenAccount>>,
+    
+    #[account(mut)]
+    pub seller_token_account: Option<Account<'info, TokenAccount>>,
+    
+    #[account(mut)]
+    pub platform_token_account: Option<Account<'info, TokenAccount>>,
+    
+    // Also moves the NFT of an NFT-backed copy
+    pub token_program: Option<Program<'info, Token>>,
+    
+    // Purchase NFT accounts, required when the listed copy is minted as an NFT
+    pub nft_mint: Option<Account<'info, Mint>>,
+    
+    /// CHECK: Checked by the token metadata program
+    pub nft_master_edition: Option<UncheckedAccount<'info>>,
+    
+    #[account(mut)]
+    pub seller_nft_account: Option<Account<'info, TokenAccount>>,
+    
+    #[account(mut)]
+    pub buyer_nft_account: Option<Account<'info, TokenAccount>>,
+    
+    pub token_metadata_program: Option<Program<'info, Metadata>>,
+}
+
+#[derive(Accounts)]
+pub struct CancelListing<'info> {
+    #[account(mut)]
+    pub seller: Signer<'info>,
+    
+    #[account(
+        mut,
+        close = seller,
+        seeds = [b"listing", listing.purchase.as_ref()],
+        bump,
+        has_one = seller @ ErrorCode::NotAuthorized,
+    )]
+    pub listing: Account<'info, Listing>,
+}
+
+#[derive(Accounts)]
+pub struct CreateAuction<'info> {
+    #[account(
+        mut,
+        has_one = creator @ ErrorCode::NotAuthorized,
+    )]
+    pub content: Account<'info, Content>,
+    
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    
+    #[account(
+        seeds = [b"platform"],
+        bump
+    )]
+    pub platform: Account<'info, Platform>,
+    
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + Auction::LEN,
+        seeds = [b"auction", content.key().as_ref()],
+        bump
+    )]
+    pub auction: Account<'info, Auction>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct PlaceBid<'info> {
+    #[account(mut)]
+    pub bidder: Signer<'info>,
+    
+    /// CHECK: Receives the refund of the outbid amount, checked against the auction
+    #[account(mut)]
+    pub previous_bidder: AccountInfo<'info>,
+    
+    #[account(
+        seeds = [b"platform"],
+        bump
+    )]
+    pub platform: Account<'info, Platform>,
+    
+    #[account(
+        mut,
+        seeds = [b"auction", auction.content.as_ref()],
+        bump
+    )]
+    pub auction: Account<'info, Auction>,
+    
+    /// CHECK: Bidder's purchase record for the content, which must not hold a copy
+    #[account(
+        seeds = [b"purchase", bidder.key().as_ref(), auction.content.as_ref()],
+        bump
+    )]
+    pub bidder_purchase: UncheckedAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SettleAuction<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    #[account(mut)]
+    pub content: Account<'info, Content>,
+    
+    /// CHECK: Auction winner, checked against the auction
+    #[account(mut)]
+    pub winner: AccountInfo<'info>,
+    
+    /// CHECK: Receives the auction rent, checked against the auction
+    #[account(mut)]
+    pub creator: AccountInfo<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"creator_vault", content.creator.as_ref()],
+        bump,
+    )]
+    pub creator_vault: Account<'info, CreatorVault>,
+    
+    #[account(
+        mut,
+        seeds = [b"platform"],
+        bump
+    )]
+    pub platform: Account<'info, Platform>,
+    
+    #[account(
+        mut,
+        close = creator,
+        seeds = [b"auction", content.key().as_ref()],
+        bump,
+        has_one = content @ ErrorCode::NotAuthorized,
+        has_one = creator @ ErrorCode::NotAuthorized,
+    )]
+    pub auction: Account<'info, Auction>,
+    
+    // Reused when the wallet's earlier copy was transferred away
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + Purchase::LEN,
+        seeds = [b"purchase", winner.key().as_ref(), content.key().as_ref()],
+        bump
+    )]
+    pub winner_purchase: Account<'info, Purchase>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CancelAuction<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    
+    #[account(mut)]
+    pub content: Account<'info, Content>,
+    
+    #[account(
+        mut,
+        close = creator,
+        seeds = [b"auction", content.key().as_ref()],
+        bump,
+        has_one = creator @ ErrorCode::NotAuthorized,
+        has_one = content @ ErrorCode::NotAuthorized,
+    )]
+    pub auction: Account<'info, Auction>,
+}
+
+#[derive(Accounts)]
+pub struct MakeOffer<'info> {
+    pub content: Account<'info, Content>,
+    
+    #[account(mut)]
+    pub bidder: Signer<'info>,
+    
+    #[account(
+        seeds = [b"platform"],
+        bump
+    )]
+    pub platform: Account<'info, Platform>,
+    
+    #[account(
+        init,
+        payer = bidder,
+        space = 8 + Offer::LEN,
+        seeds = [b"offer", bidder.key().as_ref(), content.key().as_ref()],
+        bump
+    )]
+    pub offer: Account<'info, Offer>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CancelOffer<'info> {
+    #[account(mut)]
+    pub bidder: Signer<'info>,
+    
+    #[account(
+        mut,
+        close = bidder,
+        seeds = [b"offer", bidder.key().as_ref(), offer.content.as_ref()],
+        bump,
+        has_one = bidder @ ErrorCode::NotAuthorized,
+    )]
+    pub offer: Account<'info, Offer>,
+}
+
+#[derive(Accounts)]
+pub struct RefundExpiredOffer<'info> {
+    /// CHECK: Receives the escrow refund, checked against the offer
+    #[account(mut)]
+    pub bidder: AccountInfo<'info>,
+    
+    #[account(
+        mut,
+        close = bidder,
+        seeds = [b"offer", bidder.key().as_ref(), offer.content.as_ref()],
+        bump,
+        has_one = bidder @ ErrorCode::NotAuthorized,
+    )]
+    pub offer: Account<'info, Offer>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptOffer<'info> {
+    pub content: Account<'info, Content>,
+    
+    #[account(mut)]
+    pub seller: Signer<'info>,
+    
+    /// CHECK: Receives the offer account rent, checked against the offer
+    #[account(mut)]
+    pub bidder: AccountInfo<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"creator_vault", content.creator.as_ref()],
+        bump,
+    )]
+    pub creator_vault: Account<'info, CreatorVault>,
+    
+    #[account(
+        mut,
+        seeds = [b"platform"],
+        bump
+    )]
+    pub platform: Account<'info, Platform>,
+    
+    #[account(
+        mut,
+        close = bidder,
+        seeds = [b"offer", bidder.key().as_ref(), content.key().as_ref()],
+        bump,
+        has_one = bidder @ ErrorCode::NotAuthorized,
+        has_one = content @ ErrorCode::NotAuthorized,
+    )]
+    pub offer: Account<'info, Offer>,
+    
+    #[account(
+        mut,
+        seeds = [b"purchase", seller.key().as_ref(), content.key().as_ref()],
+        bump,
+        constraint = seller_purchase.buyer == seller.key() @ ErrorCode::NotAuthorized,
+    )]
+    pub seller_purchase: Account<'info, Purchase>,
+    
+    /// CHECK: Seller's listing of the copy, closed with the sale if it exists
+    #[account(
+        mut,
+        seeds = [b"listing", seller_purchase.key().as_ref()],
         bump
     )]
-    pub platform: Account<'info, Platform>,
+    pub seller_listing: UncheckedAccount<'info>,
     
-    #[account(mut)]
-    pub authority: Signer<'info>,
+    // Reused when the wallet's earlier copy was transferred away; the seller fronts the
+    // rent of a new record and is repaid from the bidder's deposit in the offer escrow
+    #[account(
+        init_if_needed,
+        payer = seller,
+        space = 8 + Purchase::LEN,
+        seeds = [b"purchase", bidder.key().as_ref(), content.key().as_ref()],
+        bump
+    )]
+    pub buyer_purchase: Account<'info, Purchase>,
     
     pub system_program: Program<'info, System>,
 }
 
-#[derive(Accounts)]
-#[instruction(content_id: String)]
-pub struct CreateContent<'info> {
-    #[account(
-        init,
-        payer = creator,
-        space = 8 + Content::LEN,
-        seeds = [b"content", content_id.as_bytes()],
-        bump
-    )]
-    pub content: Account<'info, Content>,
-    
-    #[account(mut)]
-    pub creator: Signer<'info>,
-    
-    #[account(
-        mut,
-        seeds = [b"platform"],
-        bump
-    )]
-    pub platform: Account<'info, Platform>,
-    
-    pub system_program: Program<'info, System>,
+#[account]
+pub struct Platform {
+    pub authority: Pubkey,
+    pub pending_authority: Option<Pubkey>,
+    pub platform_fee_bps: u16,
+    pub min_platform_fee: Option<u64>,
+    pub max_platform_fee: Option<u64>,
+    pub total_content_count: u64,
+    pub total_sales_volume: u64,
+    pub total_withdrawn: u64,
+    pub paused: u32,
+    pub subscription_pool_balance: u64,
+    // SOL/USD price feed that USD prices convert at, None until the authority sets one
+    pub price_oracle: Option<PriceOracle>,
+    // SPL tokens content and subscriptions may be priced in
+    pub accepted_mints: Vec<AcceptedMint>,
+}
+
+impl Platform {
+    pub const MAX_ACCEPTED_MINTS: usize = 8;
+    pub const LEN: usize = 32 + 33 + 2 + 9 + 9 + 8 + 8 + 8 + 4 + 8 + 1 + PriceOracle::LEN
+        + 4 + Self::MAX_ACCEPTED_MINTS * AcceptedMint::LEN; // 33 for Option<Pubkey>, 9 for each Option<u64>
+
+    // Platform fee for a payment of `amount` lamports, clamped to the configured bounds
+    pub fn calculate_fee(&self, amount: u64) -> Result<u64> {
+        fees::platform_fee(
+            amount,
+            self.platform_fee_bps,
+            self.min_platform_fee,
+            self.max_platform_fee,
+        )
+    }
+
+    // Fails if `instruction` (one of the `pause::PAUSE_*` bits) is currently halted
+    pub fn require_not_paused(&self, instruction: u32) -> Result<()> {
+        pause::require_not_paused(self.paused, instruction)
+    }
+
+    // Price oracle USD prices convert at, which must be set before any content is priced in USD
+    pub fn price_oracle(&self) -> Result<&PriceOracle> {
+        self.price_oracle
+            .as_ref()
+            .ok_or_else(|| error!(ErrorCode::PriceOracleNotSet))
+    }
+
+    // Fails unless new prices may be set in `payment_mint`; None means lamports
+    pub fn require_accepted_mint(&self, payment_mint: Option<Pubkey>) -> Result<()> {
+        if let Some(mint) = payment_mint {
+            require!(
+                self.accepted_mints
+                    .iter()
+                    .any(|accepted| accepted.mint == mint && accepted.active),
+                ErrorCode::MintNotAccepted
+            );
+        }
+        Ok(())
+    }
+
+    pub fn accepted_mint_mut(&mut self, mint: Pubkey) -> Result<&mut AcceptedMint> {
+        self.accepted_mints
+            .iter_mut()
+            .find(|accepted| accepted.mint == mint)
+            .ok_or(ErrorCode::MintNotAccepted.into())
+    }
+
+    // Adds lamport sales to the running volume; token sales are reported through events
+    pub fn record_sale_volume(&mut self, payment_mint: Option<Pubkey>, amount: u64) -> Result<()> {
+        if payment_mint.is_none() {
+            self.total_sales_volume = fees::checked_add(self.total_sales_volume, amount)?;
+        }
+        Ok(())
+    }
+}
+
+// An SPL token accepted for payments. Retired mints keep their entry so the
+// subscription pool held in them can still be claimed
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct AcceptedMint {
+    pub mint: Pubkey,
+    pub active: bool,
+    // Creators' unclaimed subscription revenue held in the platform's token account
+    pub subscription_pool_balance: u64,
+}
+
+impl AcceptedMint {
+    pub const LEN: usize = 32 + 1 + 8;
+}
+
+#[account]
+pub struct Content {
+    pub id: String,
+    pub creator: Pubkey,
+    pub arweave_id: String,
+    pub price: u64,
+    pub royalty_percentage: u8,
+    pub sales_count: u64,
+    pub active: bool,
+    pub created_at: i64,
+    // New fields
+    pub rental_enabled: bool,
+    pub rental_price: u64,
+    pub rental_duration: i64,
+    pub subscription_tier: u8,
+    pub declining_price: Option<DecliningPrice>,
+    // Limited editions: sales stop once `sales_count` reaches `max_supply`
+    pub max_supply: Option<u64>,
+    pub reserved_editions: u64,
+    // Collaborators sharing the creator's proceeds; empty means the creator keeps everything
+    pub revenue_splits: Vec<RevenueSplit>,
+    pub revenue_splits_locked: bool,
+    // Seconds after a purchase during which the buyer may ask for a refund
+    pub refund_window: Option<i64>,
+    // SPL token the prices are denominated in; None for lamports
+    pub payment_mint: Option<Pubkey>,
+    // Prices in USD, converted to lamports at purchase time
+    pub usd_pricing: Option<UsdPricing>,
+    // Metaplex collection that purchase NFTs are minted into; None when NFTs are off
+    pub nft_collection: Option<Pubkey>,
+}
+
+impl Content {
+    pub const MAX_REVENUE_SPLITS: usize = 8;
+    pub const LEN: usize = 64 + 32 + 64 + 8 + 1 + 8 + 1 + 8 + 1 + 8 + 8 + 1 + 1 + DecliningPrice::LEN + 9 + 8
+        + 4 + Self::MAX_REVENUE_SPLITS * RevenueSplit::LEN + 1 + 9 + 33 + 1 + UsdPricing::LEN + 33;
+
+    // Whether an edition is left that is not already sold or held back for an auction
+    pub fn has_unreserved_supply(&self) -> bool {
+        match self.max_supply {
+            Some(max_supply) => {
+                self.sales_count.saturating_add(self.reserved_editions) < max_supply
+            }
+            None => true,
+        }
+    }
+
+    // Counts a primary sale and returns its edition number, starting from 1
+    pub fn record_sale(&mut self) -> Result<u64> {
+        self.sales_count = fees::checked_add(self.sales_count, 1)?;
+        Ok(self.sales_count)
+    }
+
+    // Undo a refunded sale, so its edition can be sold again
+    pub fn reverse_sale(&mut self) -> Result<()> {
+        self.sales_count = self
+            .sales_count
+            .checked_sub(1)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    pub fn release_reserved_edition(&mut self) {
+        self.reserved_editions = self.reserved_editions.saturating_sub(1);
+    }
+
+    // Price a buyer pays at `now`, following the declining price schedule if one is set
+    pub fn current_price(&self, now: i64) -> Result<u64> {
+        match &self.declining_price {
+            Some(config) => fees::declining_price(
+                config.start_price,
+                config.floor_price,
+                config.start_time,
+                config.end_time,
+                config.step_interval,
+                now,
+            ),
+            None => Ok(self.price),
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct RevenueSplit {
+    pub recipient: Pubkey,
+    pub share_bps: u16,
+}
+
+impl RevenueSplit {
+    pub const LEN: usize = 32 + 2;
+}
+
+// Optional settings of a new piece of content; the defaults launch it for sale only,
+// without rentals, tier gating or a supply cap
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct ContentLaunchOptions {
+    pub rental_enabled: bool,
+    pub rental_price: u64,
+    pub rental_duration: i64,
+    pub subscription_tier: u8,
+    pub max_supply: Option<u64>,
+}
+
+// Dutch auction launch: the price falls from `start_price` to `floor_price` over the window
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct DecliningPrice {
+    pub start_price: u64,
+    pub floor_price: u64,
+    pub start_time: i64,
+    pub end_time: i64,
+    // Length of each price step in seconds, or 0 for a continuous linear decline
+    pub step_interval: i64,
+}
+
+impl DecliningPrice {
+    pub const LEN: usize = 8 + 8 + 8 + 8 + 8;
+}
+
+// USD prices in cents, converted at the platform's SOL/USD price feed; buyers bound the
+// conversion with their own maximum price
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct UsdPricing {
+    pub price_usd_cents: u64,
+    pub rental_price_usd_cents: u64,
+}
+
+impl UsdPricing {
+    pub const LEN: usize = 8 + 8;
+}
+
+// Pyth-compatible SOL/USD price account and the oracle program that must own it, which
+// differs per cluster: Pyth is FsJ3A3u2vn5cTVofAjvy6y5kwABJAqYWpe4975bi2epH on
+// mainnet-beta and gSbePebfvPy7tRqimPoVecS2UsBvYv46ynrzWocc92s on devnet
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PriceOracle {
+    pub program_id: Pubkey,
+    pub sol_usd_feed: Pubkey,
+}
+
+impl PriceOracle {
+    pub const LEN: usize = 32 + 32;
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub enum PurchaseType {
+    FullPurchase,
+    Rental,
+}
+
+#[account]
+pub struct Purchase {
+    pub buyer: Pubkey,
+    pub content: Pubkey,
+    pub price: u64,
+    pub timestamp: i64,
+    pub resale_rights: bool,
+    pub purchase_type: PurchaseType,
+    pub expiration: Option<i64>,
+    // Provenance: the record this copy was bought from, and the record it was sold to
+    pub acquired_from: Option<Pubkey>,
+    pub transferred_to: Option<Pubkey>,
+    // Serial number of an owned copy, e.g. 3 for "#3 of 50"; None for rentals
+    pub edition_number: Option<u64>,
+    // Payment held in this account until the content's refund window passes
+    pub refund_escrow: Option<RefundEscrow>,
+    // NFT minted for this copy; whoever holds it owns the copy
+    pub nft_mint: Option<Pubkey>,
+    // Non-transferable Token-2022 pass issued for a rental
+    pub pass_mint: Option<Pubkey>,
+}
+
+impl Purchase {
+    pub const LEN: usize = 32 + 32 + 8 + 8 + 1 + 1 + 9 + 33 + 33 + 9 + 1 + RefundEscrow::LEN + 33 + 33; // 9 for each Option<i64/u64>, 33 for each Option<Pubkey>
+
+    pub fn refund_escrow_mut(&mut self) -> Result<&mut RefundEscrow> {
+        self.refund_escrow
+            .as_mut()
+            .ok_or(ErrorCode::PurchaseNotInEscrow.into())
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RefundStatus {
+    Open,
+    Requested,
+    Rejected,
+    Disputed,
+    // The arbiter sided with the creator
+    Upheld,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct RefundEscrow {
+    pub platform_fee: u64,
+    pub release_after: i64,
+    pub status: RefundStatus,
+}
+
+impl RefundEscrow {
+    pub const LEN: usize = 8 + 8 + 1;
+    // Time either side gets to answer a refund request or rejection
+    pub const RESPONSE_WINDOW: i64 = 3 * 24 * 60 * 60;
+
+    // Pushes the release time out so the other side has a full response window
+    pub fn extend_for_response(&mut self, now: i64) -> Result<()> {
+        let respond_by = now
+            .checked_add(Self::RESPONSE_WINDOW)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        self.release_after = self.release_after.max(respond_by);
+        Ok(())
+    }
+
+    pub fn is_releasable(&self, now: i64) -> bool {
+        match self.status {
+            RefundStatus::Open | RefundStatus::Rejected => now >= self.release_after,
+            RefundStatus::Upheld => true,
+            RefundStatus::Requested | RefundStatus::Disputed => false,
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AccessKind {
+    Purchase,
+    Rental,
+    Subscription,
+}
+
+// Returned by `verify_access` so gatekeepers can read it from a simulated transaction
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct AccessInfo {
+    pub kind: AccessKind,
+    pub expiration: Option<i64>,
+}
+
+#[account]
+pub struct Listing {
+    pub seller: Pubkey,
+    pub content: Pubkey,
+    pub purchase: Pubkey,
+    pub price: u64,
+    pub created_at: i64,
+    // Unit of `price`: the content's payment mint when listed, None for lamports
+    pub payment_mint: Option<Pubkey>,
+    // When the seller acquired the listed copy, matching its purchase record's timestamp
+    pub acquired_at: i64,
+}
+
+impl Listing {
+    pub const LEN: usize = 32 + 32 + 32 + 8 + 8 + 33 + 8;
+}
+
+#[account]
+pub struct Auction {
+    pub content: Pubkey,
+    pub creator: Pubkey,
+    pub reserve_price: u64,
+    pub start_time: i64,
+    pub end_time: i64,
+    pub min_increment: u64,
+    pub extension_window: i64,
+    pub highest_bidder: Option<Pubkey>,
+    pub highest_bid: u64,
+    pub bid_count: u64,
+}
+
+impl Auction {
+    pub const LEN: usize = 32 + 32 + 8 + 8 + 8 + 8 + 8 + 33 + 8 + 8; // 33 for Option<Pubkey>
+}
+
+#[account]
+pub struct Offer {
+    pub bidder: Pubkey,
+    pub content: Pubkey,
+    pub amount: u64,
+    pub created_at: i64,
+    pub expires_at: i64,
+}
+
+impl Offer {
+    pub const LEN: usize = 32 + 32 + 8 + 8 + 8;
+}
+
+#[account]
+pub struct Subscription {
+    pub subscriber: Pubkey,
+    pub tier: u8,
+    pub start_time: i64,
+    pub expiration_time: i64,
+    pub active: bool,
+    // What was paid for the time left when last paid: `paid_price` for `paid_period` seconds
+    pub paid_price: u64,
+    pub paid_period: i64,
+}
+
+impl Subscription {
+    pub const LEN: usize = 32 + 1 + 8 + 8 + 1 + 8 + 8;
+
+    // Seconds left of a running subscription
+    pub fn remaining(&self, now: i64) -> i64 {
+        if self.active {
+            self.expiration_time.saturating_sub(now)
+        } else {
+            0
+        }
+    }
+}
+
+#[account]
+pub struct SubscriptionTierConfig {
+    pub tier: u8,
+    pub price: u64,
+    pub period: i64,
+    pub active: bool,
+    pub name: String,
+    // SPL token the price is denominated in; None for lamports
+    pub payment_mint: Option<Pubkey>,
+}
+
+impl SubscriptionTierConfig {
+    pub const MAX_NAME_LEN: usize = 32;
+    pub const LEN: usize = 1 + 8 + 8 + 1 + 4 + Self::MAX_NAME_LEN + 33;
+
+    // Seed for the tier's token revenue pool; empty for tiers priced in lamports
+    pub fn payment_mint_seed(&self) -> &[u8] {
+        self.payment_mint.as_ref().map_or(&[], |mint| mint.as_ref())
+    }
+}
+
+#[account]
+pub struct AccessReceipt {
+    pub subscriber: Pubkey,
+    pub content: Pubkey,
+    pub tier: u8,
+    pub access_count: u64,
+    pub first_accessed_at: i64,
+    pub last_accessed_at: i64,
+}
+
+impl AccessReceipt {
+    pub const LEN: usize = 32 + 32 + 1 + 8 + 8 + 8;
+}
+
+// Marks a subscriber as counted towards a creator's share of one epoch
+#[account]
+pub struct EpochAccess {
+    pub subscriber: Pubkey,
+    pub creator: Pubkey,
+    pub epoch: u64,
+}
+
+impl EpochAccess {
+    pub const LEN: usize = 32 + 32 + 8;
+}
+
+#[account]
+pub struct RevenueEpoch {
+    pub epoch: u64,
+    pub total_revenue: u64,
+    pub total_accesses: u64,
+    pub total_claimed: u64,
+    // Accesses whose creators have claimed, and unclaimable revenue returned to the platform
+    pub claimed_accesses: u64,
+    pub total_swept: u64,
+}
+
+impl RevenueEpoch {
+    pub const LEN: usize = 8 + 8 + 8 + 8 + 8 + 8;
+
+    // Subscription revenue is pooled and shared out in fixed 30-day epochs
+    pub const DURATION: i64 = 30 * 24 * 60 * 60;
+
+    pub fn index_at(timestamp: i64) -> u64 {
+        timestamp.max(0) as u64 / Self::DURATION as u64
+    }
+
+    pub fn current() -> Result<u64> {
+        Ok(Self::index_at(Clock::get()?.unix_timestamp))
+    }
+}
+
+#[account]
+pub struct CreatorEpochStats {
+    pub creator: Pubkey,
+    pub epoch: u64,
+    pub access_count: u64,
+    pub claimed: bool,
+    pub amount_claimed: u64,
+}
+
+impl CreatorEpochStats {
+    pub const LEN: usize = 32 + 8 + 8 + 1 + 8;
+}
+
+// Creators' share of one epoch's subscription revenue paid in an SPL token
+#[account]
+pub struct TokenRevenuePool {
+    pub epoch: u64,
+    pub mint: Pubkey,
+    pub total_revenue: u64,
+    pub total_claimed: u64,
+    // Accesses whose creators have claimed, and unclaimable revenue returned to the platform
+    pub claimed_accesses: u64,
+    pub total_swept: u64,
+}
+
+impl TokenRevenuePool {
+    pub const LEN: usize = 8 + 32 + 8 + 8 + 8 + 8;
+}
+
+// Marks a creator's claim on a token revenue pool
+#[account]
+pub struct TokenRevenueClaim {
+    pub creator: Pubkey,
+    pub epoch: u64,
+    pub mint: Pubkey,
+    pub amount: u64,
+}
+
+impl TokenRevenueClaim {
+    pub const LEN: usize = 32 + 8 + 32 + 8;
+}
+
+// Holds a creator's sale, rental and royalty proceeds until they claim them. The
+// counters track lamports; token proceeds sit in the vault's associated token accounts
+// and are counted per mint. Revenue split recipients other than the creator are paid
+// straight to their wallet or token account and have no vault
+#[account]
+pub struct CreatorVault {
+    pub creator: Pubkey,
+    pub total_earned: u64,
+    pub total_claimed: u64,
+    pub token_earnings: Vec<TokenEarnings>,
+}
+
+impl CreatorVault {
+    // A vault can only be paid in mints the platform has accepted
+    pub const LEN: usize =
+        32 + 8 + 8 + 4 + Platform::MAX_ACCEPTED_MINTS * TokenEarnings::LEN;
+
+    pub fn record_earnings(&mut self, payment_mint: Option<Pubkey>, amount: u64) -> Result<()> {
+        let Some(mint) = payment_mint else {
+            self.total_earned = fees::checked_add(self.total_earned, amount)?;
+            return Ok(());
+        };
+
+        if !self.token_earnings.iter().any(|earnings| earnings.mint == mint) {
+            require!(
+                self.token_earnings.len() < Platform::MAX_ACCEPTED_MINTS,
+                ErrorCode::TooManyAcceptedMints
+            );
+            self.token_earnings.push(TokenEarnings {
+                mint,
+                total_earned: 0,
+                total_claimed: 0,
+            });
+        }
+        let earnings = self.token_earnings_mut(mint)?;
+        earnings.total_earned = fees::checked_add(earnings.total_earned, amount)?;
+        Ok(())
+    }
+
+    pub fn unclaimed(&self) -> Result<u64> {
+        self.total_earned
+            .checked_sub(self.total_claimed)
+            .ok_or(ErrorCode::ArithmeticOverflow.into())
+    }
+
+    pub fn token_earnings_mut(&mut self, mint: Pubkey) -> Result<&mut TokenEarnings> {
+        self.token_earnings
+            .iter_mut()
+            .find(|earnings| earnings.mint == mint)
+            .ok_or_else(|| error!(ErrorCode::NoEarningsToClaim))
+    }
+}
+
+// Lifetime proceeds of a creator vault in one SPL token
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct TokenEarnings {
+    pub mint: Pubkey,
+    pub total_earned: u64,
+    pub total_claimed: u64,
+}
+
+impl TokenEarnings {
+    pub const LEN: usize = 32 + 8 + 8;
+
+    pub fn unclaimed(&self) -> Result<u64> {
+        self.total_earned
+            .checked_sub(self.total_claimed)
+            .ok_or(ErrorCode::ArithmeticOverflow.into())
+    }
+}
+
+#[event]
+pub struct PlatformInitialized {
+    pub authority: Pubkey,
+    pub platform_fee_bps: u16,
+    pub min_platform_fee: Option<u64>,
+    pub max_platform_fee: Option<u64>,
+    pub timestamp: i64,
 }
 
-#[derive(Accounts)]
-pub struct UpdateContent<'info> {
-    #[account(
-        mut,
-        has_one = creator @ ErrorCode::NotAuthorized,
-    )]
-    pub content: Account<'info, Content>,
-    
-    #[account(mut)]
-    pub creator: Signer<'info>,
-    
-    pub system_program: Program<'info, System>,
+#[event]
+pub struct PlatformFeeUpdated {
+    pub authority: Pubkey,
+    pub previous_fee_bps: u16,
+    pub platform_fee_bps: u16,
+    pub min_platform_fee: Option<u64>,
+    pub max_platform_fee: Option<u64>,
+    pub timestamp: i64,
 }
 
-#[derive(Accounts)]
-pub struct PurchaseContent<'info> {
-    #[account(mut)]
-    pub content: Account<'info, Content>,
-    
-    #[account(mut)]
-    pub buyer: Signer<'info>,
-    
-    #[account(
-        mut,
-        constraint = creator.key() == content.creator @ ErrorCode::NotAuthorized
-    )]
-    pub creator: AccountInfo<'info>,
-    
-    #[account(
-        mut,
-        seeds = [b"platform"],
-        bump
-    )]
-    pub platform: Account<'info, Platform>,
-    
-    #[account(
-        init,
-        payer = buyer,
-        space = 8 + Purchase::LEN,
-        seeds = [b"purchase", buyer.key().as_ref(), content.key().as_ref()],
-        bump
-    )]
-    pub purchase: Account<'info, Purchase>,
-    
-    pub system_program: Program<'info, System>,
+#[event]
+pub struct AuthorityTransferProposed {
+    pub authority: Pubkey,
+    pub pending_authority: Pubkey,
+    pub timestamp: i64,
 }
 
-#[derive(Accounts)]
-pub struct RentContent<'info> {
-    #[account(mut)]
-    pub content: Account<'info, Content>,
-    
-    #[account(mut)]
-    pub renter: Signer<'info>,
-    
-    #[account(
-        mut,
-        constraint = creator.key() == content.creator @ ErrorCode::NotAuthorized
-    )]
-    pub creator: AccountInfo<'info>,
-    
-    #[account(
-        mut,
-        seeds = [b"platform"],
-        bump
-    )]
-    pub platform: Account<'info, Platform>,
-    
-    #[account(
-        init,
-        payer = renter,
-        space = 8 + Purchase::LEN,
-        seeds = [b"rental", renter.key().as_ref(), content.key().as_ref()],
-        bump
-    )]
-    pub rental: Account<'info, Purchase>,
-    
-    pub system_program: Program<'info, System>,
+#[event]
+pub struct AuthorityTransferAccepted {
+    pub previous_authority: Pubkey,
+    pub authority: Pubkey,
+    pub timestamp: i64,
 }
 
-#[derive(Accounts)]
-pub struct Subscribe<'info> {
-    #[account(mut)]
-    pub subscriber: Signer<'info>,
-    
-    #[account(
-        mut,
-        seeds = [b"platform"],
-        bump
-    )]
-    pub platform: Account<'info, Platform>,
-    
-    #[account(
-        init,
-        payer = subscriber,
-        space = 8 + Subscription::LEN,
-        seeds = [b"subscription", subscriber.key().as_ref()],
-        bump
-    )]
-    pub subscription: Account<'info, Subscription>,
-    
-    pub system_program: Program<'info, System>,
+#[event]
+pub struct AuthorityTransferCancelled {
+    pub authority: Pubkey,
+    pub pending_authority: Pubkey,
+    pub timestamp: i64,
 }
 
-#[derive(Accounts)]
-pub struct ResellContent<'info> {
-    #[account(mut)]
-    pub content: Account<'info, Content>,
-    
-    #[account(mut)]
-    pub seller: Signer<'info>,
-    
-    #[account(mut)]
-    pub buyer: AccountInfo<'info>,
-    
-    #[account(
-        mut,
-        constraint = creator.key() == content.creator @ ErrorCode::NotAuthorized
-    )]
-    pub creator: AccountInfo<'info>,
-    
-    #[account(
-        mut,
-        seeds = [b"platform"],
-        bump
-    )]
-    pub platform: Account<'info, Platform>,
-    
-    #[account(
-        seeds = [b"purchase", seller.key().as_ref(), content.key().as_ref()],
-        bump,
-        constraint = seller_purchase.buyer == seller.key() @ ErrorCode::NotAuthorized,
-        constraint = seller_purchase.content == content.key() @ ErrorCode::NotAuthorized,
-    )]
-    pub seller_purchase: Account<'info, Purchase>,
-    
-    #[account(
-        init,
-        payer = seller,
-        space = 8 + Purchase::LEN,
-        seeds = [b"purchase", buyer.key().as_ref(), content.key().as_ref()],
-        bump
-    )]
-    pub buyer_purchase: Account<'info, Purchase>,
-    
-    pub system_program: Program<'info, System>,
+#[event]
+pub struct PlatformPauseUpdated {
+    pub authority: Pubkey,
+    pub previous_paused: u32,
+    pub paused: u32,
+    pub timestamp: i64,
 }
 
-#[account]
-pub struct Platform {
+#[event]
+pub struct PlatformTokenFeesWithdrawn {
     pub authority: Pubkey,
-    pub platform_fee: u64,
-    pub total_content_count: u64,
-    pub total_sales_volume: u64,
+    pub destination: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AcceptedMintUpdated {
+    pub authority: Pubkey,
+    pub mint: Pubkey,
+    pub active: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PriceOracleUpdated {
+    pub authority: Pubkey,
+    pub price_oracle: Option<PriceOracle>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PlatformFeesWithdrawn {
+    pub authority: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub total_withdrawn: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ContentCreated {
+    pub content_id: String,
+    pub creator: Pubkey,
+    pub price: u64,
+    pub royalty_percentage: u8,
+    pub rental_enabled: bool,
+    pub subscription_tier: u8,
+    pub max_supply: Option<u64>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ContentUpdated {
+    pub content_id: String,
+    pub creator: Pubkey,
+    pub price: u64,
+    pub active: bool,
+    pub rental_enabled: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DecliningPriceUpdated {
+    pub content_id: String,
+    pub creator: Pubkey,
+    pub declining_price: Option<DecliningPrice>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct UsdPricingUpdated {
+    pub content_id: String,
+    pub creator: Pubkey,
+    pub usd_pricing: Option<UsdPricing>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PaymentMintUpdated {
+    pub content_id: String,
+    pub creator: Pubkey,
+    pub payment_mint: Option<Pubkey>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RevenueSplitsUpdated {
+    pub content_id: String,
+    pub creator: Pubkey,
+    pub revenue_splits: Vec<RevenueSplit>,
+    pub locked: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RefundWindowUpdated {
+    pub content_id: String,
+    pub creator: Pubkey,
+    pub refund_window: Option<i64>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct NftCollectionCreated {
+    pub content_id: String,
+    pub creator: Pubkey,
+    pub collection_mint: Pubkey,
+    pub name: String,
+    pub timestamp: i64,
 }
 
-impl Platform {
-    pub const LEN: usize = 32 + 8 + 8 + 8;
+#[event]
+pub struct PurchaseNftMinted {
+    pub content_id: String,
+    pub purchase: Pubkey,
+    pub buyer: Pubkey,
+    pub nft_mint: Pubkey,
+    pub edition_number: Option<u64>,
+    pub timestamp: i64,
 }
 
-#[account]
-pub struct Content {
-    pub id: String,
+#[event]
+pub struct ContentPurchased {
+    pub content_id: String,
+    pub buyer: Pubkey,
     pub creator: Pubkey,
-    pub arweave_id: String,
     pub price: u64,
-    pub royalty_percentage: u8,
-    pub sales_count: u64,
-    pub active: bool,
-    pub created_at: i64,
-    // New fields
-    pub rental_enabled: bool,
-    pub rental_price: u64,
-    pub rental_duration: i64,
-    pub subscription_tier: u8,
-}
-
-impl Content {
-    pub const LEN: usize = 64 + 32 + 64 + 8 + 1 + 8 + 1 + 8 + 1 + 8 + 8 + 1;
+    pub platform_fee: u64,
+    pub edition_number: u64,
+    pub max_supply: Option<u64>,
+    pub refund_deadline: Option<i64>,
+    pub payment_mint: Option<Pubkey>,
+    pub timestamp: i64,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
-pub enum PurchaseType {
-    FullPurchase,
-    Rental,
+#[event]
+pub struct RefundRequested {
+    pub purchase: Pubkey,
+    pub buyer: Pubkey,
+    pub content: Pubkey,
+    pub price: u64,
+    pub respond_by: i64,
+    pub timestamp: i64,
 }
 
-#[account]
-pub struct Purchase {
+#[event]
+pub struct RefundApproved {
+    pub purchase: Pubkey,
     pub buyer: Pubkey,
     pub content: Pubkey,
+    pub approved_by: Pubkey,
     pub price: u64,
     pub timestamp: i64,
-    pub resale_rights: bool,
-    pub purchase_type: PurchaseType,
-    pub expiration: Option<i64>,
 }
 
-impl Purchase {
-    pub const LEN: usize = 32 + 32 + 8 + 8 + 1 + 1 + 9; // 9 for Option<i64>
+#[event]
+pub struct RefundRejected {
+    pub purchase: Pubkey,
+    pub buyer: Pubkey,
+    pub content: Pubkey,
+    pub escalate_by: i64,
+    pub timestamp: i64,
 }
 
-#[account]
-pub struct Subscription {
-    pub subscriber: Pubkey,
-    pub tier: u8,
-    pub start_time: i64,
-    pub expiration_time: i64,
-    pub active: bool,
+#[event]
+pub struct RefundEscalated {
+    pub purchase: Pubkey,
+    pub buyer: Pubkey,
+    pub content: Pubkey,
+    pub arbiter: Pubkey,
+    pub timestamp: i64,
 }
 
-impl Subscription {
-    pub const LEN: usize = 32 + 1 + 8 + 8 + 1;
+#[event]
+pub struct DisputeResolved {
+    pub purchase: Pubkey,
+    pub buyer: Pubkey,
+    pub arbiter: Pubkey,
+    pub refunded: bool,
+    pub timestamp: i64,
 }
 
 #[event]
-pub struct PlatformInitialized {
-    pub authority: Pubkey,
+pub struct EscrowReleased {
+    pub purchase: Pubkey,
+    pub buyer: Pubkey,
+    pub creator: Pubkey,
+    pub price: u64,
     pub platform_fee: u64,
     pub timestamp: i64,
 }
 
 #[event]
-pub struct ContentCreated {
+pub struct ContentRented {
     pub content_id: String,
+    pub renter: Pubkey,
     pub creator: Pubkey,
     pub price: u64,
-    pub royalty_percentage: u8,
-    pub rental_enabled: bool,
-    pub subscription_tier: u8,
+    pub platform_fee: u64,
+    pub expiration: i64,
+    pub payment_mint: Option<Pubkey>,
     pub timestamp: i64,
 }
 
 #[event]
-pub struct ContentUpdated {
+pub struct RentalExtended {
     pub content_id: String,
-    pub creator: Pubkey,
+    pub renter: Pubkey,
+    pub periods: u32,
     pub price: u64,
-    pub active: bool,
-    pub rental_enabled: bool,
+    pub platform_fee: u64,
+    pub previous_expiration: i64,
+    pub expiration: i64,
+    pub payment_mint: Option<Pubkey>,
     pub timestamp: i64,
 }
 
 #[event]
-pub struct ContentPurchased {
+pub struct RentalClosed {
+    pub rental: Pubkey,
+    pub renter: Pubkey,
+    pub content: Pubkey,
+    pub expired: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RentalPassIssued {
     pub content_id: String,
-    pub buyer: Pubkey,
-    pub creator: Pubkey,
-    pub price: u64,
+    pub rental: Pubkey,
+    pub renter: Pubkey,
+    pub pass_mint: Pubkey,
+    pub expiration: i64,
     pub timestamp: i64,
 }
 
 #[event]
-pub struct ContentRented {
+pub struct RentalPassBurned {
     pub content_id: String,
+    pub rental: Pubkey,
     pub renter: Pubkey,
-    pub creator: Pubkey,
-    pub price: u64,
+    pub pass_mint: Pubkey,
     pub expiration: i64,
     pub timestamp: i64,
 }
@@ -718,6 +6370,33 @@ pub struct ContentResold {
     pub creator: Pubkey,
     pub price: u64,
     pub royalty_amount: u64,
+    pub platform_fee: u64,
+    pub payment_mint: Option<Pubkey>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SubscriptionTierCreated {
+    pub tier: u8,
+    pub price: u64,
+    pub period: i64,
+    pub name: String,
+    pub payment_mint: Option<Pubkey>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SubscriptionTierUpdated {
+    pub tier: u8,
+    pub price: u64,
+    pub period: i64,
+    pub name: String,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SubscriptionTierRetired {
+    pub tier: u8,
     pub timestamp: i64,
 }
 
@@ -726,10 +6405,228 @@ pub struct SubscriptionCreated {
     pub subscriber: Pubkey,
     pub tier: u8,
     pub price: u64,
+    pub platform_fee: u64,
+    pub expiration: i64,
+    pub payment_mint: Option<Pubkey>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SubscriptionRenewed {
+    pub subscriber: Pubkey,
+    pub tier: u8,
+    pub price: u64,
+    pub platform_fee: u64,
+    pub expiration: i64,
+    pub payment_mint: Option<Pubkey>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SubscriptionTierChanged {
+    pub subscriber: Pubkey,
+    pub previous_tier: u8,
+    pub tier: u8,
+    pub price: u64,
+    pub credit: u64,
+    pub platform_fee: u64,
+    pub expiration: i64,
+    pub payment_mint: Option<Pubkey>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SubscriptionCancelled {
+    pub subscriber: Pubkey,
+    pub tier: u8,
+    pub expiration: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ContentAccessedViaSubscription {
+    pub content_id: String,
+    pub content: Pubkey,
+    pub creator: Pubkey,
+    pub subscriber: Pubkey,
+    pub tier: u8,
+    pub access_count: u64,
     pub expiration: i64,
     pub timestamp: i64,
 }
 
+#[event]
+pub struct SubscriptionRevenueClaimed {
+    pub creator: Pubkey,
+    pub epoch: u64,
+    pub access_count: u64,
+    pub total_accesses: u64,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SubscriptionRevenueSwept {
+    pub epoch: u64,
+    pub mint: Option<Pubkey>,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct EarningsClaimed {
+    pub creator: Pubkey,
+    pub amount: u64,
+    pub total_earned: u64,
+    pub total_claimed: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TokenEarningsClaimed {
+    pub creator: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub total_earned: u64,
+    pub total_claimed: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TokenSubscriptionRevenueClaimed {
+    pub creator: Pubkey,
+    pub epoch: u64,
+    pub mint: Pubkey,
+    pub access_count: u64,
+    pub total_accesses: u64,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ListingCreated {
+    pub content_id: String,
+    pub listing: Pubkey,
+    pub seller: Pubkey,
+    pub price: u64,
+    pub payment_mint: Option<Pubkey>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ListingPurchased {
+    pub content_id: String,
+    pub listing: Pubkey,
+    pub seller: Pubkey,
+    pub buyer: Pubkey,
+    pub creator: Pubkey,
+    pub price: u64,
+    pub royalty_amount: u64,
+    pub platform_fee: u64,
+    pub payment_mint: Option<Pubkey>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ListingCancelled {
+    pub listing: Pubkey,
+    pub seller: Pubkey,
+    pub content: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AuctionCreated {
+    pub content_id: String,
+    pub auction: Pubkey,
+    pub creator: Pubkey,
+    pub reserve_price: u64,
+    pub start_time: i64,
+    pub end_time: i64,
+    pub min_increment: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BidPlaced {
+    pub auction: Pubkey,
+    pub content: Pubkey,
+    pub bidder: Pubkey,
+    pub amount: u64,
+    pub end_time: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AuctionSettled {
+    pub content_id: String,
+    pub auction: Pubkey,
+    pub winner: Pubkey,
+    pub creator: Pubkey,
+    pub price: u64,
+    pub platform_fee: u64,
+    pub edition_number: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AuctionVoided {
+    pub content_id: String,
+    pub auction: Pubkey,
+    pub winner: Pubkey,
+    pub refund: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AuctionCancelled {
+    pub auction: Pubkey,
+    pub content: Pubkey,
+    pub creator: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct OfferMade {
+    pub content_id: String,
+    pub offer: Pubkey,
+    pub bidder: Pubkey,
+    pub amount: u64,
+    pub expires_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct OfferCancelled {
+    pub offer: Pubkey,
+    pub bidder: Pubkey,
+    pub content: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct OfferRefunded {
+    pub offer: Pubkey,
+    pub bidder: Pubkey,
+    pub content: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct OfferAccepted {
+    pub content_id: String,
+    pub offer: Pubkey,
+    pub seller: Pubkey,
+    pub bidder: Pubkey,
+    pub creator: Pubkey,
+    pub price: u64,
+    pub royalty_amount: u64,
+    pub platform_fee: u64,
+    pub timestamp: i64,
+}
+
 #[error_code]
 pub enum ErrorCode {
     #[msg("Royalty percentage must be between 0 and 100")]
@@ -748,7 +6645,7 @@ pub enum ErrorCode {
     InvalidArweaveId,
     #[msg("Price must be greater than 0")]
     InvalidPrice,
-    #[msg("Platform fee must be greater than 0")]
+    #[msg("Platform fee cannot exceed 10000 basis points")]
     InvalidFeeAmount,
     #[msg("Rental is not enabled for this content")]
     RentalNotEnabled,
@@ -760,5 +6657,157 @@ pub enum ErrorCode {
     PurchaseExpired,
     #[msg("Invalid subscription tier")]
     InvalidSubscriptionTier,
+    #[msg("Withdrawal amount must be greater than 0")]
+    InvalidWithdrawalAmount,
+    #[msg("Platform balance above the rent-exempt minimum is too low")]
+    InsufficientPlatformBalance,
+    #[msg("Arithmetic overflow")]
+    ArithmeticOverflow,
+    #[msg("Minimum platform fee cannot exceed the maximum platform fee")]
+    InvalidFeeBounds,
+    #[msg("Price is too low to cover royalty and platform fees")]
+    PriceBelowFees,
+    #[msg("Pending authority must differ from the current authority")]
+    InvalidPendingAuthority,
+    #[msg("No authority transfer is pending")]
+    NoPendingAuthority,
+    #[msg("This instruction is paused by the platform")]
+    PlatformPaused,
+    #[msg("Subscription period must be greater than 0")]
+    InvalidSubscriptionPeriod,
+    #[msg("Subscription tier name must be between 1 and 32 bytes")]
+    InvalidTierName,
+    #[msg("Subscription tier is not active")]
+    SubscriptionTierInactive,
+    #[msg("Subscription is not active")]
+    SubscriptionNotActive,
+    #[msg("Subscription is already on this tier")]
+    SameSubscriptionTier,
+    #[msg("Subscription has expired")]
+    SubscriptionExpired,
+    #[msg("Subscription tier is too low for this content")]
+    SubscriptionTierTooLow,
+    #[msg("Content is not available through subscriptions")]
+    ContentNotInSubscription,
+    #[msg("Revenue epoch has not closed yet")]
+    RevenueEpochNotClosed,
+    #[msg("Revenue for this epoch has already been claimed")]
+    RevenueAlreadyClaimed,
+    #[msg("Creators can still claim this revenue")]
+    RevenueStillClaimable,
+    #[msg("No unclaimable revenue left to sweep")]
+    NoRevenueToSweep,
+    #[msg("Content has not been purchased")]
+    NotPurchased,
+    #[msg("Rental has expired")]
+    RentalExpired,
+    #[msg("Listing price does not match the expected price")]
+    ListingPriceMismatch,
+    #[msg("Purchase has been transferred to a new owner")]
+    PurchaseTransferred,
+    #[msg("Offer expiration must be in the future")]
+    InvalidOfferExpiration,
+    #[msg("Offer has expired")]
+    OfferExpired,
+    #[msg("Offer has not expired yet")]
+    OfferNotExpired,
+    #[msg("Auction must end after it starts and in the future")]
+    InvalidAuctionTimes,
+    #[msg("Minimum bid increment must be greater than 0")]
+    InvalidBidIncrement,
+    #[msg("Auction has not started yet")]
+    AuctionNotStarted,
+    #[msg("Auction has ended")]
+    AuctionEnded,
+    #[msg("Auction has not ended yet")]
+    AuctionNotEnded,
+    #[msg("Bid is below the reserve price or minimum increment")]
+    BidTooLow,
+    #[msg("Auction already has bids")]
+    AuctionHasBids,
+    #[msg("Auction has no winning bid")]
+    AuctionHasNoBids,
+    #[msg("Content has already been purchased")]
+    AlreadyPurchased,
+    #[msg("Declining price must fall from a start price to a lower floor within its window")]
+    InvalidDecliningPrice,
+    #[msg("Max supply must be greater than 0")]
+    InvalidMaxSupply,
+    #[msg("Content is sold out")]
+    ContentSoldOut,
+    #[msg("Revenue splits need distinct recipients with non-zero shares summing to 10000 basis points")]
+    InvalidRevenueSplits,
+    #[msg("Revenue splits are locked after the first sale")]
+    RevenueSplitsLocked,
+    #[msg("Revenue split recipient accounts are missing, out of order or not writable")]
+    InvalidSplitRecipient,
+    #[msg("No earnings to claim")]
+    NoEarningsToClaim,
+    #[msg("Refund window must be positive")]
+    InvalidRefundWindow,
+    #[msg("Purchase has no payment held in escrow")]
+    PurchaseNotInEscrow,
+    #[msg("Purchase payment is still held in escrow")]
+    PurchaseInEscrow,
+    #[msg("Refund window has closed")]
+    RefundWindowClosed,
+    #[msg("Refund is not in a state that allows this action")]
+    InvalidRefundStatus,
+    #[msg("Escrow cannot be released yet")]
+    EscrowNotReleasable,
+    #[msg("Payment mint is not accepted by the platform")]
+    MintNotAccepted,
+    #[msg("Too many accepted payment mints")]
+    TooManyAcceptedMints,
+    #[msg("SPL token payment accounts are missing")]
+    MissingTokenAccounts,
+    #[msg("Purchase NFT accounts are missing")]
+    MissingNftAccounts,
+    #[msg("Token account does not match the payment mint or owner")]
+    InvalidTokenAccount,
+    #[msg("Refund windows are only supported for content priced in SOL")]
+    TokenRefundsUnsupported,
+    #[msg("Auctions and offers are only supported for content priced in SOL")]
+    TokenEscrowUnsupported,
+    #[msg("Payment mints do not match")]
+    PaymentMintMismatch,
+    #[msg("USD pricing cannot be combined with declining prices or token payments")]
+    IncompatiblePricingModes,
+    #[msg("Price feed is missing, does not match the content or is not trading")]
+    InvalidPriceFeed,
+    #[msg("Price feed is stale")]
+    StalePriceFeed,
+    #[msg("Price feed confidence interval is too wide")]
+    PriceConfidenceTooWide,
+    #[msg("Price exceeds the buyer's maximum")]
+    PriceAboveMaximum,
+    #[msg("NFT name, symbol or URI exceeds Metaplex limits")]
+    InvalidNftMetadata,
+    #[msg("Content already has an NFT collection")]
+    NftCollectionExists,
+    #[msg("Content does not mint purchase NFTs")]
+    NftsNotEnabled,
+    #[msg("An NFT has already been minted for this purchase")]
+    NftAlreadyMinted,
+    #[msg("Purchase is held as an NFT and can only be resold through a listing")]
+    NftBackedPurchase,
+    #[msg("Purchase NFT is not held by this wallet")]
+    NftNotHeld,
+    #[msg("A rental pass has already been issued for this rental")]
+    RentalPassExists,
+    #[msg("Rental has no pass")]
+    NoRentalPass,
+    #[msg("Rental has not expired yet")]
+    RentalNotExpired,
+    #[msg("Rental pass must be burned with burn_expired_rental first")]
+    RentalPassOutstanding,
+    #[msg("Rental must be extended by at least one period")]
+    InvalidRentalPeriods,
+    #[msg("Listing was made for a copy the seller no longer holds")]
+    StaleListing,
+    #[msg("Claim window for this revenue epoch has passed")]
+    ClaimWindowClosed,
+    #[msg("Platform has no price oracle for USD pricing")]
+    PriceOracleNotSet,
 }
 
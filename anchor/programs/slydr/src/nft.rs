@@ -0,0 +1,71 @@
+// Metaplex metadata field limits, in bytes
+pub const MAX_NAME_LEN: usize = 32;
+pub const MAX_SYMBOL_LEN: usize = 10;
+pub const MAX_URI_LEN: usize = 200;
+
+// Name of a purchase NFT: the collection name and the copy's edition number, e.g.
+// "Album #3". The collection name is shortened first so the edition number survives
+pub fn purchase_nft_name(collection_name: &str, edition_number: Option<u64>) -> String {
+    match edition_number {
+        Some(edition_number) => {
+            let suffix = format!(" #{}", edition_number);
+            let prefix = truncate(collection_name, MAX_NAME_LEN.saturating_sub(suffix.len()));
+            format!("{}{}", prefix, suffix)
+        }
+        None => truncate(collection_name, MAX_NAME_LEN).to_string(),
+    }
+}
+
+// Longest prefix of `name` within `max_len` bytes that does not split a character
+pub fn truncate(name: &str, max_len: usize) -> &str {
+    let mut end = name.len().min(max_len);
+    while !name.is_char_boundary(end) {
+        end -= 1;
+    }
+    &name[..end]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn names_copies_after_the_collection_and_edition() {
+        assert_eq!(purchase_nft_name("Album", Some(3)), "Album #3");
+        assert_eq!(purchase_nft_name("Album", None), "Album");
+    }
+
+    #[test]
+    fn shortens_the_collection_name_to_keep_the_edition() {
+        let collection_name = "A".repeat(MAX_NAME_LEN);
+        let name = purchase_nft_name(&collection_name, Some(1_234));
+        assert_eq!(name.len(), MAX_NAME_LEN);
+        assert!(name.ends_with(" #1234"));
+
+        assert_eq!(purchase_nft_name(&"B".repeat(40), None), "B".repeat(MAX_NAME_LEN));
+    }
+
+    #[test]
+    fn truncates_without_splitting_characters() {
+        // Each "é" is two bytes, so 32 bytes hold 16 of them
+        assert_eq!(truncate(&"é".repeat(20), MAX_NAME_LEN), "é".repeat(16));
+        assert_eq!(truncate("aé", 2), "a");
+        assert_eq!(truncate("short", MAX_NAME_LEN), "short");
+    }
+
+    proptest! {
+        #[test]
+        fn names_fit_metaplex_limits(
+            collection_name in "\\PC{0,32}",
+            edition_number in proptest::option::of(any::<u64>()),
+        ) {
+            let name = purchase_nft_name(&collection_name, edition_number);
+            prop_assert!(name.len() <= MAX_NAME_LEN);
+            if let Some(edition_number) = edition_number {
+                let suffix = format!(" #{}", edition_number);
+                prop_assert!(name.ends_with(&suffix));
+            }
+        }
+    }
+}
@@ -0,0 +1,239 @@
+use anchor_lang::prelude::*;
+
+use crate::fees::MAX_BASIS_POINTS;
+use crate::ErrorCode;
+
+// Pyth v2 price account layout; only the fields needed to price in USD are read
+const MAGIC: u32 = 0xa1b2c3d4;
+const VERSION: u32 = 2;
+const ACCOUNT_TYPE_PRICE: u32 = 3;
+const STATUS_TRADING: u32 = 1;
+
+const MAGIC_OFFSET: usize = 0;
+const VERSION_OFFSET: usize = 4;
+const ACCOUNT_TYPE_OFFSET: usize = 8;
+const EXPO_OFFSET: usize = 20;
+const TIMESTAMP_OFFSET: usize = 96;
+const AGG_PRICE_OFFSET: usize = 208;
+const AGG_CONF_OFFSET: usize = 216;
+const AGG_STATUS_OFFSET: usize = 224;
+
+// Bytes of a price account up to and including the aggregate price
+pub const PRICE_ACCOUNT_LEN: usize = 240;
+
+// Oldest feed update accepted, in seconds
+pub const MAX_PRICE_AGE: i64 = 60;
+// Widest confidence interval accepted, in basis points of the price
+pub const MAX_CONFIDENCE_BPS: u64 = 200;
+
+const LAMPORTS_PER_SOL: u128 = 1_000_000_000;
+const CENTS_PER_DOLLAR: u128 = 100;
+
+// SOL/USD aggregate price: `price * 10^expo` dollars, plus or minus `conf` in the same units
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PriceFeed {
+    pub price: i64,
+    pub conf: u64,
+    pub expo: i32,
+    pub publish_time: i64,
+}
+
+impl PriceFeed {
+    // Fails if the price is not positive, older than `max_age` seconds at `now`, or
+    // its confidence interval is wider than `max_confidence_bps` of the price
+    pub fn checked(self, now: i64, max_age: i64, max_confidence_bps: u64) -> Result<Self> {
+        require!(self.price > 0, ErrorCode::InvalidPriceFeed);
+        require!(
+            now.saturating_sub(self.publish_time) <= max_age,
+            ErrorCode::StalePriceFeed
+        );
+        require!(
+            self.conf as u128 * MAX_BASIS_POINTS as u128
+                <= max_confidence_bps as u128 * self.price as u128,
+            ErrorCode::PriceConfidenceTooWide
+        );
+
+        Ok(self)
+    }
+}
+
+// Parses a price account, rejecting any not owned by the oracle program: anyone can
+// write an account with the Pyth layout
+pub fn load_price_account(
+    oracle_program: &Pubkey,
+    owner: &Pubkey,
+    data: &[u8],
+) -> Result<PriceFeed> {
+    require_keys_eq!(*owner, *oracle_program, ErrorCode::InvalidPriceFeed);
+    parse_price_account(data)
+}
+
+pub fn parse_price_account(data: &[u8]) -> Result<PriceFeed> {
+    require!(data.len() >= PRICE_ACCOUNT_LEN, ErrorCode::InvalidPriceFeed);
+    require!(
+        read_u32(data, MAGIC_OFFSET) == MAGIC
+            && read_u32(data, VERSION_OFFSET) == VERSION
+            && read_u32(data, ACCOUNT_TYPE_OFFSET) == ACCOUNT_TYPE_PRICE,
+        ErrorCode::InvalidPriceFeed
+    );
+    require!(
+        read_u32(data, AGG_STATUS_OFFSET) == STATUS_TRADING,
+        ErrorCode::InvalidPriceFeed
+    );
+
+    Ok(PriceFeed {
+        price: read_u64(data, AGG_PRICE_OFFSET) as i64,
+        conf: read_u64(data, AGG_CONF_OFFSET),
+        expo: read_u32(data, EXPO_OFFSET) as i32,
+        publish_time: read_u64(data, TIMESTAMP_OFFSET) as i64,
+    })
+}
+
+// Builds a trading price account in the Pyth layout, e.g. to load as a mock feed
+// into a local validator, owned by the platform's oracle program. Only built for tests
+// and local tooling, never into the deployed program
+#[cfg(any(test, feature = "mock-oracle"))]
+pub fn mock_price_account(price: i64, conf: u64, expo: i32, publish_time: i64) -> Vec<u8> {
+    let mut data = vec![0u8; PRICE_ACCOUNT_LEN];
+    data[MAGIC_OFFSET..MAGIC_OFFSET + 4].copy_from_slice(&MAGIC.to_le_bytes());
+    data[VERSION_OFFSET..VERSION_OFFSET + 4].copy_from_slice(&VERSION.to_le_bytes());
+    data[ACCOUNT_TYPE_OFFSET..ACCOUNT_TYPE_OFFSET + 4]
+        .copy_from_slice(&ACCOUNT_TYPE_PRICE.to_le_bytes());
+    data[EXPO_OFFSET..EXPO_OFFSET + 4].copy_from_slice(&expo.to_le_bytes());
+    data[TIMESTAMP_OFFSET..TIMESTAMP_OFFSET + 8].copy_from_slice(&publish_time.to_le_bytes());
+    data[AGG_PRICE_OFFSET..AGG_PRICE_OFFSET + 8].copy_from_slice(&price.to_le_bytes());
+    data[AGG_CONF_OFFSET..AGG_CONF_OFFSET + 8].copy_from_slice(&conf.to_le_bytes());
+    data[AGG_STATUS_OFFSET..AGG_STATUS_OFFSET + 4].copy_from_slice(&STATUS_TRADING.to_le_bytes());
+    data
+}
+
+// Lamports worth `usd_cents` at the feed's SOL/USD price, rounded up so the seller
+// never receives less than the USD price
+pub fn usd_cents_to_lamports(usd_cents: u64, feed: &PriceFeed) -> Result<u64> {
+    require!(feed.price > 0, ErrorCode::InvalidPriceFeed);
+
+    let scale = 10u128
+        .checked_pow(feed.expo.unsigned_abs())
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+    let mut numerator = (usd_cents as u128)
+        .checked_mul(LAMPORTS_PER_SOL)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+    let mut denominator = (feed.price as u128) * CENTS_PER_DOLLAR;
+    if feed.expo < 0 {
+        numerator = numerator.checked_mul(scale).ok_or(ErrorCode::ArithmeticOverflow)?;
+    } else {
+        denominator = denominator.checked_mul(scale).ok_or(ErrorCode::ArithmeticOverflow)?;
+    }
+
+    u64::try_from(numerator.div_ceil(denominator)).map_err(|_| error!(ErrorCode::ArithmeticOverflow))
+}
+
+fn read_u32(data: &[u8], offset: usize) -> u32 {
+    u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap())
+}
+
+fn read_u64(data: &[u8], offset: usize) -> u64 {
+    u64::from_le_bytes(data[offset..offset + 8].try_into().unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    // $150.00 per SOL with Pyth's usual exponent
+    const SOL_PRICE: i64 = 150_00000000;
+    const EXPO: i32 = -8;
+
+    #[test]
+    fn mock_account_round_trips() {
+        let data = mock_price_account(SOL_PRICE, 5_000_000, EXPO, 1_000);
+        assert_eq!(
+            parse_price_account(&data).unwrap(),
+            PriceFeed {
+                price: SOL_PRICE,
+                conf: 5_000_000,
+                expo: EXPO,
+                publish_time: 1_000,
+            }
+        );
+    }
+
+    #[test]
+    fn rejects_accounts_that_are_not_trading_prices() {
+        let data = mock_price_account(SOL_PRICE, 0, EXPO, 0);
+        assert!(parse_price_account(&data[..PRICE_ACCOUNT_LEN - 1]).is_err());
+
+        let mut wrong_magic = data.clone();
+        wrong_magic[MAGIC_OFFSET] ^= 1;
+        assert!(parse_price_account(&wrong_magic).is_err());
+
+        let mut halted = data.clone();
+        halted[AGG_STATUS_OFFSET] = 0;
+        assert!(parse_price_account(&halted).is_err());
+    }
+
+    #[test]
+    fn rejects_price_accounts_not_owned_by_the_oracle() {
+        let oracle_program = Pubkey::new_unique();
+        let data = mock_price_account(SOL_PRICE, 0, EXPO, 0);
+        assert!(load_price_account(&oracle_program, &oracle_program, &data).is_ok());
+        assert_eq!(
+            load_price_account(&oracle_program, &Pubkey::new_unique(), &data).unwrap_err(),
+            ErrorCode::InvalidPriceFeed.into()
+        );
+    }
+
+    #[test]
+    fn converts_usd_to_lamports() {
+        let feed = parse_price_account(&mock_price_account(SOL_PRICE, 0, EXPO, 0)).unwrap();
+        // $15.00 at $150/SOL is 0.1 SOL
+        assert_eq!(usd_cents_to_lamports(15_00, &feed).unwrap(), 100_000_000);
+        // $1.00 at $150/SOL is 6_666_666.67 lamports, rounded up
+        assert_eq!(usd_cents_to_lamports(1_00, &feed).unwrap(), 6_666_667);
+
+        let positive_expo = PriceFeed { price: 15, conf: 0, expo: 1, publish_time: 0 };
+        assert_eq!(usd_cents_to_lamports(15_00, &positive_expo).unwrap(), 100_000_000);
+    }
+
+    #[test]
+    fn enforces_staleness_and_confidence_bounds() {
+        let feed = PriceFeed { price: SOL_PRICE, conf: 0, expo: EXPO, publish_time: 1_000 };
+        assert!(feed.checked(1_000 + MAX_PRICE_AGE, MAX_PRICE_AGE, MAX_CONFIDENCE_BPS).is_ok());
+        assert_eq!(
+            feed.checked(1_001 + MAX_PRICE_AGE, MAX_PRICE_AGE, MAX_CONFIDENCE_BPS)
+                .unwrap_err(),
+            ErrorCode::StalePriceFeed.into()
+        );
+
+        // 2% of $150 is $3
+        let at_bound = PriceFeed { conf: 3_00000000, ..feed };
+        assert!(at_bound.checked(1_000, MAX_PRICE_AGE, MAX_CONFIDENCE_BPS).is_ok());
+        let too_wide = PriceFeed { conf: 3_00000001, ..feed };
+        assert_eq!(
+            too_wide.checked(1_000, MAX_PRICE_AGE, MAX_CONFIDENCE_BPS).unwrap_err(),
+            ErrorCode::PriceConfidenceTooWide.into()
+        );
+
+        let negative = PriceFeed { price: -1, ..feed };
+        assert!(negative.checked(1_000, MAX_PRICE_AGE, MAX_CONFIDENCE_BPS).is_err());
+    }
+
+    proptest! {
+        #[test]
+        fn conversion_never_undercharges(
+            usd_cents in 0u64..1_000_000_000,
+            price in 1i64..100_000_000_000_000,
+        ) {
+            let feed = PriceFeed { price, conf: 0, expo: EXPO, publish_time: 0 };
+            let lamports = usd_cents_to_lamports(usd_cents, &feed).unwrap() as u128;
+
+            // lamports * price >= usd_cents * 10^15, and one lamport less falls short
+            let target = usd_cents as u128 * 1_000_000_000_000_000;
+            prop_assert!(lamports * price as u128 >= target);
+            if lamports > 0 {
+                prop_assert!((lamports - 1) * (price as u128) < target);
+            }
+        }
+    }
+}
@@ -0,0 +1,782 @@
+use anchor_lang::prelude::*;
+
+use crate::ErrorCode;
+
+// Bits of `Platform.paused`, one per instruction that can be halted
+pub const PAUSE_CREATE_CONTENT: u32 = 1 << 0;
+pub const PAUSE_UPDATE_CONTENT: u32 = 1 << 1;
+pub const PAUSE_PURCHASE: u32 = 1 << 2;
+pub const PAUSE_RENTAL: u32 = 1 << 3;
+pub const PAUSE_SUBSCRIBE: u32 = 1 << 4;
+pub const PAUSE_RESALE: u32 = 1 << 5;
+pub const PAUSE_SUBSCRIPTION_ACCESS: u32 = 1 << 6;
+pub const PAUSE_CLAIM_SUBSCRIPTION_REVENUE: u32 = 1 << 7;
+pub const PAUSE_AUCTION: u32 = 1 << 8;
+pub const PAUSE_CLAIM_EARNINGS: u32 = 1 << 9;
+pub const PAUSE_REFUND: u32 = 1 << 10;
+
+// Halts every pausable instruction, including ones added later
+pub const PAUSE_ALL: u32 = u32::MAX;
+
+// Fails with `PlatformPaused` if `instruction` is halted in the `paused` bitmask
+pub fn require_not_paused(paused: u32, instruction: u32) -> Result<()> {
+    require!(paused & instruction == 0, ErrorCode::PlatformPaused);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_accounts::account_info;
+    use crate::*;
+    use anchor_lang::solana_program::program_pack::Pack;
+    use anchor_lang::Discriminator;
+    use anchor_spl::metadata::mpl_token_metadata::types::Key;
+    use anchor_spl::token::spl_token;
+
+    const INSTRUCTIONS: [u32; 11] = [
+        PAUSE_CREATE_CONTENT,
+        PAUSE_UPDATE_CONTENT,
+        PAUSE_PURCHASE,
+        PAUSE_RENTAL,
+        PAUSE_SUBSCRIBE,
+        PAUSE_RESALE,
+        PAUSE_SUBSCRIPTION_ACCESS,
+        PAUSE_CLAIM_SUBSCRIPTION_REVENUE,
+        PAUSE_AUCTION,
+        PAUSE_CLAIM_EARNINGS,
+        PAUSE_REFUND,
+    ];
+
+    #[test]
+    fn nothing_is_rejected_when_unpaused() {
+        for instruction in INSTRUCTIONS {
+            assert!(require_not_paused(0, instruction).is_ok());
+        }
+    }
+
+    #[test]
+    fn every_instruction_is_rejected_when_fully_paused() {
+        for instruction in INSTRUCTIONS {
+            assert_eq!(
+                require_not_paused(PAUSE_ALL, instruction).unwrap_err(),
+                ErrorCode::PlatformPaused.into()
+            );
+        }
+    }
+
+    #[test]
+    fn pausing_one_instruction_leaves_the_others_running() {
+        for paused in INSTRUCTIONS {
+            for instruction in INSTRUCTIONS {
+                let result = require_not_paused(paused, instruction);
+                assert_eq!(result.is_err(), paused == instruction);
+            }
+        }
+    }
+
+    // Stand-ins for the accounts of an instruction context. Every handler checks its pause
+    // bit before touching its accounts, so they only need to pass Anchor's own checks
+    trait Fake {
+        fn fake() -> Self;
+    }
+
+    fn fake<T: Fake>() -> T {
+        T::fake()
+    }
+
+    fn leak<T>(value: T) -> &'static T {
+        Box::leak(Box::new(value))
+    }
+
+    const LAMPORTS: u64 = 1_000_000_000;
+
+    // Data that deserializes as an account of the type
+    trait FakeData {
+        fn data() -> Vec<u8>;
+    }
+
+    macro_rules! zeroed_program_accounts {
+        ($($account:ty),* $(,)?) => {$(
+            impl FakeData for $account {
+                fn data() -> Vec<u8> {
+                    let mut data = <$account>::DISCRIMINATOR.to_vec();
+                    data.resize(8 + <$account>::LEN, 0);
+                    data
+                }
+            }
+        )*};
+    }
+
+    zeroed_program_accounts!(
+        AccessReceipt,
+        Auction,
+        Content,
+        CreatorEpochStats,
+        CreatorVault,
+        EpochAccess,
+        Listing,
+        Offer,
+        Platform,
+        Purchase,
+        RevenueEpoch,
+        Subscription,
+        SubscriptionTierConfig,
+        TokenRevenueClaim,
+        TokenRevenuePool,
+    );
+
+    impl FakeData for Mint {
+        fn data() -> Vec<u8> {
+            let mut data = vec![0; spl_token::state::Mint::LEN];
+            let mint = spl_token::state::Mint {
+                is_initialized: true,
+                ..Default::default()
+            };
+            mint.pack_into_slice(&mut data);
+            data
+        }
+    }
+
+    impl FakeData for TokenAccount {
+        fn data() -> Vec<u8> {
+            let mut data = vec![0; spl_token::state::Account::LEN];
+            let account = spl_token::state::Account {
+                state: spl_token::state::AccountState::Initialized,
+                ..Default::default()
+            };
+            account.pack_into_slice(&mut data);
+            data
+        }
+    }
+
+    impl FakeData for MetadataAccount {
+        fn data() -> Vec<u8> {
+            let mut data = vec![0; 1024];
+            data[0] = Key::MetadataV1 as u8;
+            data
+        }
+    }
+
+    impl<T: FakeData + AccountSerialize + AccountDeserialize + Owner + Clone> Fake
+        for Account<'static, T>
+    {
+        fn fake() -> Self {
+            Account::try_from(account_info(T::owner(), LAMPORTS, &T::data())).unwrap()
+        }
+    }
+
+    impl Fake for Signer<'static> {
+        fn fake() -> Self {
+            let mut info = account_info(System::id(), LAMPORTS, &[]).clone();
+            info.is_signer = true;
+            Signer::try_from(leak(info)).unwrap()
+        }
+    }
+
+    impl<P: Id + Clone> Fake for Program<'static, P> {
+        fn fake() -> Self {
+            let mut info = account_info(Pubkey::default(), LAMPORTS, &[]).clone();
+            info.key = leak(P::id());
+            info.executable = true;
+            Program::try_from(leak(info)).unwrap()
+        }
+    }
+
+    impl Fake for Sysvar<'static, Rent> {
+        fn fake() -> Self {
+            let rent = Rent::default();
+            let mut data = rent.lamports_per_byte_year.to_le_bytes().to_vec();
+            data.extend_from_slice(&rent.exemption_threshold.to_le_bytes());
+            data.push(rent.burn_percent);
+            let mut info = account_info(Pubkey::default(), LAMPORTS, &data).clone();
+            info.key = leak(anchor_lang::solana_program::sysvar::rent::ID);
+            Sysvar::from_account_info(leak(info)).unwrap()
+        }
+    }
+
+    impl Fake for UncheckedAccount<'static> {
+        fn fake() -> Self {
+            UncheckedAccount::try_from(account_info(System::id(), LAMPORTS, &[]))
+        }
+    }
+
+    impl Fake for AccountInfo<'static> {
+        fn fake() -> Self {
+            account_info(System::id(), LAMPORTS, &[]).clone()
+        }
+    }
+
+    impl<T> Fake for Option<T> {
+        fn fake() -> Self {
+            None
+        }
+    }
+
+    fn balances(accounts: &[AccountInfo]) -> Vec<(u64, Vec<u8>)> {
+        accounts
+            .iter()
+            .map(|account| (account.lamports(), account.data.borrow().to_vec()))
+            .collect()
+    }
+
+    struct Call {
+        handler: &'static str,
+        result: Result<()>,
+        accounts_unchanged: bool,
+    }
+
+    // Calls a handler on stand-in accounts, with the platform's pause bits set to `paused`
+    macro_rules! call {
+        (
+            $paused:expr,
+            $handler:ident($($arg:expr),*),
+            $accounts:ident { $($field:ident),* $(,)? }
+        ) => {{
+            let mut platform: Account<Platform> = fake();
+            platform.paused = $paused;
+            let mut accounts = $accounts { platform, $($field: fake()),* };
+            let before = balances(&accounts.to_account_infos());
+            let result = slydr_program::$handler(
+                Context::new(&crate::ID, &mut accounts, &[], Default::default()),
+                $($arg),*
+            );
+            Call {
+                handler: stringify!($handler),
+                result,
+                accounts_unchanged: balances(&accounts.to_account_infos()) == before,
+            }
+        }};
+    }
+
+    type Handler = fn(u32) -> Call;
+
+    // Every handler that can be halted, with the bit that halts it
+    fn handlers() -> Vec<(u32, Handler)> {
+        vec![
+            (PAUSE_CREATE_CONTENT, |paused| {
+                call!(
+                    paused,
+                    create_content(String::new(), String::new(), 0, 0, Default::default()),
+                    CreateContent {
+                        content,
+                        creator,
+                        creator_vault,
+                        system_program
+                    }
+                )
+            }),
+            (PAUSE_UPDATE_CONTENT, |paused| {
+                call!(
+                    paused,
+                    update_content(None, None, None, None, None, None),
+                    UpdateContent {
+                        content,
+                        creator,
+                        system_program
+                    }
+                )
+            }),
+            (PAUSE_UPDATE_CONTENT, |paused| {
+                call!(
+                    paused,
+                    set_declining_price(None),
+                    SetDecliningPrice { content, creator }
+                )
+            }),
+            (PAUSE_UPDATE_CONTENT, |paused| {
+                call!(
+                    paused,
+                    set_refund_window(None),
+                    SetRefundWindow { content, creator }
+                )
+            }),
+            (PAUSE_UPDATE_CONTENT, |paused| {
+                call!(
+                    paused,
+                    set_usd_pricing(None),
+                    SetUsdPricing {
+                        content,
+                        creator,
+                        price_feed
+                    }
+                )
+            }),
+            (PAUSE_UPDATE_CONTENT, |paused| {
+                call!(
+                    paused,
+                    set_payment_mint(None),
+                    SetPaymentMint { content, creator }
+                )
+            }),
+            (PAUSE_UPDATE_CONTENT, |paused| {
+                call!(
+                    paused,
+                    set_revenue_splits(Vec::new(), false),
+                    SetRevenueSplits { content, creator }
+                )
+            }),
+            (PAUSE_UPDATE_CONTENT, |paused| {
+                call!(
+                    paused,
+                    create_nft_collection(String::new(), String::new(), String::new()),
+                    CreateNftCollection {
+                        creator,
+                        content,
+                        collection_mint,
+                        collection_token_account,
+                        collection_metadata,
+                        collection_master_edition,
+                        token_metadata_program,
+                        token_program,
+                        associated_token_program,
+                        system_program,
+                        rent,
+                    }
+                )
+            }),
+            (PAUSE_PURCHASE, |paused| {
+                call!(
+                    paused,
+                    mint_purchase_nft(),
+                    MintPurchaseNft {
+                        buyer,
+                        content,
+                        purchase,
+                        nft_mint,
+                        buyer_nft_account,
+                        nft_metadata,
+                        nft_master_edition,
+                        collection_mint,
+                        collection_metadata,
+                        collection_master_edition,
+                        token_metadata_program,
+                        token_program,
+                        associated_token_program,
+                        system_program,
+                        rent,
+                    }
+                )
+            }),
+            (PAUSE_PURCHASE, |paused| {
+                call!(
+                    paused,
+                    purchase_content(None),
+                    PurchaseContent {
+                        content,
+                        buyer,
+                        creator_vault,
+                        purchase,
+                        system_program,
+                        buyer_token_account,
+                        creator_vault_token_account,
+                        platform_token_account,
+                        token_program,
+                        price_feed,
+                    }
+                )
+            }),
+            (PAUSE_REFUND, |paused| {
+                call!(paused, request_refund(), RequestRefund { buyer, purchase })
+            }),
+            (PAUSE_REFUND, |paused| {
+                call!(
+                    paused,
+                    approve_refund(),
+                    ApproveRefund {
+                        creator,
+                        content,
+                        buyer,
+                        purchase
+                    }
+                )
+            }),
+            (PAUSE_REFUND, |paused| {
+                call!(
+                    paused,
+                    reject_refund(),
+                    RejectRefund {
+                        creator,
+                        content,
+                        purchase
+                    }
+                )
+            }),
+            (PAUSE_REFUND, |paused| {
+                call!(
+                    paused,
+                    escalate_refund(),
+                    EscalateRefund { buyer, purchase }
+                )
+            }),
+            (PAUSE_REFUND, |paused| {
+                call!(
+                    paused,
+                    release_escrow(),
+                    ReleaseEscrow {
+                        content,
+                        purchase,
+                        creator_vault
+                    }
+                )
+            }),
+            (PAUSE_RENTAL, |paused| {
+                call!(
+                    paused,
+                    rent_content(None),
+                    RentContent {
+                        content,
+                        renter,
+                        creator_vault,
+                        rental,
+                        system_program,
+                        renter_token_account,
+                        creator_vault_token_account,
+                        platform_token_account,
+                        token_program,
+                        price_feed,
+                    }
+                )
+            }),
+            (PAUSE_RENTAL, |paused| {
+                call!(
+                    paused,
+                    extend_rental(0, None),
+                    ExtendRental {
+                        content,
+                        renter,
+                        creator_vault,
+                        rental,
+                        system_program,
+                        renter_token_account,
+                        creator_vault_token_account,
+                        platform_token_account,
+                        token_program,
+                        price_feed,
+                        pass_mint,
+                        token_2022_program,
+                    }
+                )
+            }),
+            (PAUSE_RENTAL, |paused| {
+                call!(
+                    paused,
+                    issue_rental_pass(),
+                    IssueRentalPass {
+                        renter,
+                        content,
+                        rental,
+                        pass_mint,
+                        renter_pass_account,
+                        token_2022_program,
+                        associated_token_program,
+                        system_program,
+                    }
+                )
+            }),
+            (PAUSE_SUBSCRIBE, |paused| {
+                call!(
+                    paused,
+                    subscribe(0),
+                    Subscribe {
+                        subscriber,
+                        tier_config,
+                        revenue_epoch,
+                        token_revenue_pool,
+                        subscription,
+                        system_program,
+                        subscriber_token_account,
+                        platform_token_account,
+                        token_program,
+                    }
+                )
+            }),
+            (PAUSE_SUBSCRIBE, |paused| {
+                call!(
+                    paused,
+                    renew_subscription(),
+                    RenewSubscription {
+                        subscriber,
+                        tier_config,
+                        revenue_epoch,
+                        token_revenue_pool,
+                        subscription,
+                        system_program,
+                        subscriber_token_account,
+                        platform_token_account,
+                        token_program,
+                    }
+                )
+            }),
+            (PAUSE_SUBSCRIBE, |paused| {
+                call!(
+                    paused,
+                    change_subscription_tier(0),
+                    ChangeSubscriptionTier {
+                        subscriber,
+                        current_tier_config,
+                        new_tier_config,
+                        revenue_epoch,
+                        token_revenue_pool,
+                        subscription,
+                        system_program,
+                        subscriber_token_account,
+                        platform_token_account,
+                        token_program,
+                    }
+                )
+            }),
+            (PAUSE_SUBSCRIPTION_ACCESS, |paused| {
+                call!(
+                    paused,
+                    access_content_via_subscription(),
+                    AccessContentViaSubscription {
+                        content,
+                        subscriber,
+                        subscription,
+                        access_receipt,
+                        epoch_access,
+                        revenue_epoch,
+                        creator_epoch_stats,
+                        system_program,
+                    }
+                )
+            }),
+            (PAUSE_CLAIM_SUBSCRIPTION_REVENUE, |paused| {
+                call!(
+                    paused,
+                    claim_subscription_revenue(0),
+                    ClaimSubscriptionRevenue {
+                        creator,
+                        revenue_epoch,
+                        creator_epoch_stats
+                    }
+                )
+            }),
+            (PAUSE_CLAIM_EARNINGS, |paused| {
+                call!(
+                    paused,
+                    claim_earnings(),
+                    ClaimEarnings {
+                        creator,
+                        creator_vault
+                    }
+                )
+            }),
+            (PAUSE_CLAIM_SUBSCRIPTION_REVENUE, |paused| {
+                call!(
+                    paused,
+                    claim_token_subscription_revenue(0),
+                    ClaimTokenSubscriptionRevenue {
+                        creator,
+                        revenue_epoch,
+                        creator_epoch_stats,
+                        payment_mint,
+                        token_revenue_pool,
+                        token_revenue_claim,
+                        platform_token_account,
+                        creator_token_account,
+                        token_program,
+                        system_program,
+                    }
+                )
+            }),
+            (PAUSE_CLAIM_SUBSCRIPTION_REVENUE, |paused| {
+                call!(
+                    paused,
+                    sweep_subscription_revenue(0),
+                    SweepSubscriptionRevenue {
+                        authority,
+                        revenue_epoch
+                    }
+                )
+            }),
+            (PAUSE_CLAIM_SUBSCRIPTION_REVENUE, |paused| {
+                call!(
+                    paused,
+                    sweep_token_subscription_revenue(0),
+                    SweepTokenSubscriptionRevenue {
+                        authority,
+                        revenue_epoch,
+                        payment_mint,
+                        token_revenue_pool,
+                    }
+                )
+            }),
+            (PAUSE_CLAIM_EARNINGS, |paused| {
+                call!(
+                    paused,
+                    claim_token_earnings(),
+                    ClaimTokenEarnings {
+                        creator,
+                        creator_vault,
+                        payment_mint,
+                        vault_token_account,
+                        creator_token_account,
+                        token_program,
+                    }
+                )
+            }),
+            (PAUSE_RESALE, |paused| {
+                call!(
+                    paused,
+                    resell_content(0),
+                    ResellContent {
+                        content,
+                        seller,
+                        buyer,
+                        creator_vault,
+                        seller_purchase,
+                        seller_listing,
+                        buyer_purchase,
+                        system_program,
+                        buyer_token_account,
+                        creator_vault_token_account,
+                        seller_token_account,
+                        platform_token_account,
+                        token_program,
+                    }
+                )
+            }),
+            (PAUSE_RESALE, |paused| {
+                call!(
+                    paused,
+                    list_for_resale(0),
+                    ListForResale {
+                        content,
+                        seller,
+                        seller_purchase,
+                        listing,
+                        system_program
+                    }
+                )
+            }),
+            (PAUSE_RESALE, |paused| {
+                call!(
+                    paused,
+                    buy_listing(0),
+                    BuyListing {
+                        content,
+                        buyer,
+                        seller,
+                        creator_vault,
+                        listing,
+                        seller_purchase,
+                        buyer_purchase,
+                        system_program,
+                        buyer_token_account,
+                        creator_vault_token_account,
+                        seller_token_account,
+                        platform_token_account,
+                        token_program,
+                        nft_mint,
+                        nft_master_edition,
+                        seller_nft_account,
+                        buyer_nft_account,
+                        token_metadata_program,
+                    }
+                )
+            }),
+            (PAUSE_AUCTION, |paused| {
+                call!(
+                    paused,
+                    create_auction(0, 0, 0, 0, 0),
+                    CreateAuction {
+                        content,
+                        creator,
+                        auction,
+                        system_program
+                    }
+                )
+            }),
+            (PAUSE_AUCTION, |paused| {
+                call!(
+                    paused,
+                    place_bid(0),
+                    PlaceBid {
+                        bidder,
+                        previous_bidder,
+                        auction,
+                        bidder_purchase,
+                        system_program
+                    }
+                )
+            }),
+            (PAUSE_AUCTION, |paused| {
+                call!(
+                    paused,
+                    settle_auction(),
+                    SettleAuction {
+                        payer,
+                        content,
+                        winner,
+                        creator,
+                        creator_vault,
+                        auction,
+                        winner_purchase,
+                        system_program,
+                    }
+                )
+            }),
+            (PAUSE_RESALE, |paused| {
+                call!(
+                    paused,
+                    make_offer(0, 0),
+                    MakeOffer {
+                        content,
+                        bidder,
+                        offer,
+                        system_program
+                    }
+                )
+            }),
+            (PAUSE_RESALE, |paused| {
+                call!(
+                    paused,
+                    accept_offer(),
+                    AcceptOffer {
+                        content,
+                        seller,
+                        bidder,
+                        creator_vault,
+                        offer,
+                        seller_purchase,
+                        seller_listing,
+                        buyer_purchase,
+                        system_program,
+                    }
+                )
+            }),
+        ]
+    }
+
+    #[test]
+    fn every_instruction_has_a_handler_to_halt() {
+        let halted = handlers().iter().fold(0, |bits, (bit, _)| bits | bit);
+        assert_eq!(halted, INSTRUCTIONS.iter().fold(0, |bits, bit| bits | bit));
+    }
+
+    #[test]
+    fn handlers_are_halted_by_their_own_bit_only() {
+        for (bit, handler) in handlers() {
+            for paused in INSTRUCTIONS.into_iter().chain([PAUSE_ALL]) {
+                let call = handler(paused);
+                if paused & bit != 0 {
+                    assert_eq!(
+                        call.result.unwrap_err(),
+                        ErrorCode::PlatformPaused.into(),
+                        "{} ran while paused",
+                        call.handler
+                    );
+                    assert!(call.accounts_unchanged, "{} changed accounts", call.handler);
+                } else {
+                    assert!(
+                        call.result != Err(ErrorCode::PlatformPaused.into()),
+                        "{} was halted by another instruction's bit",
+                        call.handler
+                    );
+                }
+            }
+        }
+    }
+}
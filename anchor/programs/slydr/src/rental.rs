@@ -0,0 +1,127 @@
+use anchor_lang::prelude::*;
+
+use crate::{ErrorCode, Purchase};
+
+// A rental record can be reused for a new rental once the previous rental has expired
+// and its pass is burned; a record that was never rented is always free
+pub fn check_rerentable(rental: &Purchase, now: i64) -> Result<()> {
+    if rental.buyer == Pubkey::default() {
+        return Ok(());
+    }
+
+    require!(
+        rental.expiration.is_some_and(|expiration| now >= expiration),
+        ErrorCode::RentalNotExpired
+    );
+    require!(rental.pass_mint.is_none(), ErrorCode::RentalPassOutstanding);
+
+    Ok(())
+}
+
+// Expiration after extending by `periods` rental durations: from the current
+// expiration, or from `now` if it has already passed
+pub fn extended_expiration(
+    expiration: Option<i64>,
+    now: i64,
+    duration: i64,
+    periods: u32,
+) -> Result<i64> {
+    duration
+        .checked_mul(periods as i64)
+        .and_then(|extension| expiration.unwrap_or_default().max(now).checked_add(extension))
+        .ok_or(ErrorCode::ArithmeticOverflow.into())
+}
+
+// Price of `periods` rental periods at `period_price` each
+pub fn extension_price(period_price: u64, periods: u32) -> Result<u64> {
+    period_price
+        .checked_mul(periods as u64)
+        .ok_or(ErrorCode::ArithmeticOverflow.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PurchaseType;
+
+    fn rental(buyer: Pubkey, expiration: Option<i64>, pass_mint: Option<Pubkey>) -> Purchase {
+        Purchase {
+            buyer,
+            content: Pubkey::new_unique(),
+            price: 100,
+            timestamp: 0,
+            resale_rights: false,
+            purchase_type: PurchaseType::Rental,
+            expiration,
+            acquired_from: None,
+            transferred_to: None,
+            edition_number: None,
+            refund_escrow: None,
+            nft_mint: None,
+            pass_mint,
+        }
+    }
+
+    #[test]
+    fn unused_records_can_always_be_rented() {
+        assert!(check_rerentable(&rental(Pubkey::default(), None, None), 0).is_ok());
+    }
+
+    #[test]
+    fn unexpired_rentals_cannot_be_replaced() {
+        let active = rental(Pubkey::new_unique(), Some(1_000), None);
+        assert_eq!(
+            check_rerentable(&active, 999).unwrap_err(),
+            ErrorCode::RentalNotExpired.into()
+        );
+        assert!(check_rerentable(&active, 1_000).is_ok());
+
+        let without_expiration = rental(Pubkey::new_unique(), None, None);
+        assert_eq!(
+            check_rerentable(&without_expiration, i64::MAX).unwrap_err(),
+            ErrorCode::RentalNotExpired.into()
+        );
+    }
+
+    #[test]
+    fn expired_rentals_with_an_outstanding_pass_cannot_be_replaced() {
+        let with_pass = rental(Pubkey::new_unique(), Some(1_000), Some(Pubkey::new_unique()));
+        assert_eq!(
+            check_rerentable(&with_pass, 2_000).unwrap_err(),
+            ErrorCode::RentalPassOutstanding.into()
+        );
+    }
+
+    #[test]
+    fn extends_active_rentals_from_their_expiration() {
+        assert_eq!(extended_expiration(Some(1_000), 400, 100, 3).unwrap(), 1_300);
+    }
+
+    #[test]
+    fn extends_lapsed_rentals_from_now() {
+        assert_eq!(extended_expiration(Some(1_000), 5_000, 100, 3).unwrap(), 5_300);
+        assert_eq!(extended_expiration(None, 5_000, 100, 1).unwrap(), 5_100);
+    }
+
+    #[test]
+    fn rejects_extensions_that_overflow() {
+        assert_eq!(
+            extended_expiration(Some(0), 0, i64::MAX, 2).unwrap_err(),
+            ErrorCode::ArithmeticOverflow.into()
+        );
+        assert_eq!(
+            extended_expiration(Some(i64::MAX - 10), 0, 100, 1).unwrap_err(),
+            ErrorCode::ArithmeticOverflow.into()
+        );
+    }
+
+    #[test]
+    fn charges_one_price_per_period() {
+        assert_eq!(extension_price(250, 4).unwrap(), 1_000);
+        assert_eq!(extension_price(u64::MAX, 1).unwrap(), u64::MAX);
+        assert_eq!(
+            extension_price(u64::MAX / 2 + 1, 2).unwrap_err(),
+            ErrorCode::ArithmeticOverflow.into()
+        );
+    }
+}
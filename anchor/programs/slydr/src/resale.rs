@@ -0,0 +1,161 @@
+use anchor_lang::prelude::*;
+
+use crate::{ErrorCode, Listing, Purchase};
+
+// A wallet's purchase record can take a new copy if it was just created, or if the copy
+// it held has since been transferred away
+pub fn check_purchase_reusable(purchase: &Purchase) -> Result<()> {
+    require!(
+        purchase.buyer == Pubkey::default() || purchase.transferred_to.is_some(),
+        ErrorCode::AlreadyPurchased
+    );
+    Ok(())
+}
+
+// A listing is only good for the copy the seller held when listing it: a copy that left
+// through another sale and was bought back is a new acquisition
+pub fn check_listing_current(listing: &Listing, seller_purchase: &Purchase) -> Result<()> {
+    require!(
+        listing.acquired_at == seller_purchase.timestamp,
+        ErrorCode::StaleListing
+    );
+    Ok(())
+}
+
+// Move a purchased copy from the seller's record to the buyer's. The seller's listing of
+// the copy, if there is one, is closed with its rent returned to the seller, so it can't
+// be bought once the copy has left
+pub fn transfer_purchase<'info>(
+    seller_purchase: &mut Account<'info, Purchase>,
+    seller_listing: &AccountInfo<'info>,
+    seller: &AccountInfo<'info>,
+    buyer_purchase: &mut Account<'info, Purchase>,
+    buyer: Pubkey,
+    price: u64,
+    timestamp: i64,
+) -> Result<()> {
+    // Record purchase for buyer
+    buyer_purchase.buyer = buyer;
+    buyer_purchase.content = seller_purchase.content;
+    buyer_purchase.price = price;
+    buyer_purchase.timestamp = timestamp;
+    buyer_purchase.resale_rights = seller_purchase.resale_rights;
+    buyer_purchase.purchase_type = seller_purchase.purchase_type.clone();
+    buyer_purchase.expiration = seller_purchase.expiration;
+    buyer_purchase.acquired_from = Some(seller_purchase.key());
+    buyer_purchase.transferred_to = None;
+    buyer_purchase.edition_number = seller_purchase.edition_number;
+    buyer_purchase.refund_escrow = None;
+    buyer_purchase.nft_mint = seller_purchase.nft_mint;
+    buyer_purchase.pass_mint = None;
+
+    // The seller gives up access and cannot sell the same copy again
+    seller_purchase.resale_rights = false;
+    seller_purchase.transferred_to = Some(buyer_purchase.key());
+
+    if crate::load_program_account::<Listing>(seller_listing)?.is_some() {
+        crate::transfer_funds_from_escrow(
+            seller_listing,
+            &[(seller.clone(), seller_listing.lamports())],
+        )?;
+        seller_listing.assign(&System::id());
+        seller_listing.realloc(0, false)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_accounts::{account_info, program_account};
+    use crate::PurchaseType;
+
+    fn purchase(buyer: Pubkey, content: Pubkey, timestamp: i64) -> Purchase {
+        Purchase {
+            buyer,
+            content,
+            price: 100,
+            timestamp,
+            resale_rights: true,
+            purchase_type: PurchaseType::FullPurchase,
+            expiration: None,
+            acquired_from: None,
+            transferred_to: None,
+            edition_number: None,
+            refund_escrow: None,
+            nft_mint: None,
+            pass_mint: None,
+        }
+    }
+
+    #[test]
+    fn records_holding_a_copy_cannot_be_reused() {
+        let content = Pubkey::new_unique();
+        assert!(check_purchase_reusable(&purchase(Pubkey::default(), content, 0)).is_ok());
+        assert_eq!(
+            check_purchase_reusable(&purchase(Pubkey::new_unique(), content, 0)).unwrap_err(),
+            ErrorCode::AlreadyPurchased.into()
+        );
+    }
+
+    #[test]
+    fn listings_do_not_survive_the_copy_leaving() {
+        let content = Pubkey::new_unique();
+        let alice = account_info(System::id(), 0, &[]);
+        let bob = account_info(System::id(), 0, &[]);
+        let alice_record = program_account(&purchase(*alice.key, content, 100));
+        let bob_record = program_account(&purchase(Pubkey::default(), content, 0));
+        let mut alice_purchase = Account::<Purchase>::try_from(alice_record).unwrap();
+        let mut bob_purchase = Account::<Purchase>::try_from(bob_record).unwrap();
+
+        // Alice lists her copy
+        let listing = Listing {
+            seller: *alice.key,
+            content,
+            purchase: alice_purchase.key(),
+            price: 500,
+            created_at: 150,
+            payment_mint: None,
+            acquired_at: alice_purchase.timestamp,
+        };
+        let alice_listing = program_account(&listing);
+        assert!(check_listing_current(&listing, &alice_purchase).is_ok());
+
+        // She sells it to Bob through an offer instead, which closes the listing
+        transfer_purchase(
+            &mut alice_purchase,
+            alice_listing,
+            alice,
+            &mut bob_purchase,
+            *bob.key,
+            400,
+            200,
+        )
+        .unwrap();
+        assert_eq!(alice_listing.lamports(), 0);
+        assert!(alice_listing.data_is_empty());
+        assert_eq!(alice.lamports(), 1_000_000);
+
+        // Bob sells it back into Alice's record, without having listed it
+        let bob_listing = account_info(System::id(), 0, &[]);
+        assert!(check_purchase_reusable(&alice_purchase).is_ok());
+        transfer_purchase(
+            &mut bob_purchase,
+            bob_listing,
+            bob,
+            &mut alice_purchase,
+            *alice.key,
+            450,
+            300,
+        )
+        .unwrap();
+        assert_eq!(alice_purchase.transferred_to, None);
+
+        // The old listing no longer matches the copy Alice holds
+        assert_eq!(
+            check_listing_current(&listing, &alice_purchase).unwrap_err(),
+            ErrorCode::StaleListing.into()
+        );
+    }
+}
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+// The runtime stores an account's original data length just before its key and the
+// current length just before its data, which closing an account writes to
+#[repr(C)]
+struct SerializedKey {
+    original_data_len: u32,
+    key: Pubkey,
+}
+
+// An account laid out the way the runtime passes it to the program, leaked so it can be
+// handed to `Account::try_from` and instruction contexts
+pub fn account_info(owner: Pubkey, lamports: u64, data: &[u8]) -> &'static AccountInfo<'static> {
+    let key = Box::leak(Box::new(SerializedKey {
+        original_data_len: data.len() as u32,
+        key: Pubkey::new_unique(),
+    }));
+    let words = Box::leak(vec![0u64; 1 + data.len().div_ceil(8)].into_boxed_slice());
+    words[0] = data.len() as u64;
+    // SAFETY: the words are leaked, and a u64 slice can always be viewed as bytes
+    let bytes =
+        unsafe { std::slice::from_raw_parts_mut(words.as_mut_ptr() as *mut u8, words.len() * 8) };
+    let data_slice = &mut bytes[8..8 + data.len()];
+    data_slice.copy_from_slice(data);
+
+    Box::leak(Box::new(AccountInfo::new(
+        &key.key,
+        false,
+        true,
+        Box::leak(Box::new(lamports)),
+        data_slice,
+        Box::leak(Box::new(owner)),
+        false,
+        0,
+    )))
+}
+
+pub fn program_account<T: AccountSerialize>(value: &T) -> &'static AccountInfo<'static> {
+    let mut data = Vec::new();
+    value.try_serialize(&mut data).unwrap();
+    account_info(crate::ID, 1_000_000, &data)
+}
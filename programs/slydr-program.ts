@@ -26,8 +26,16 @@ export const idl = {
       ],
       args: [
         {
-          name: "platformFee",
-          type: "u64",
+          name: "platformFeeBps",
+          type: "u16",
+        },
+        {
+          name: "minPlatformFee",
+          type: { option: "u64" },
+        },
+        {
+          name: "maxPlatformFee",
+          type: { option: "u64" },
         },
       ],
     },
@@ -44,6 +52,11 @@ export const idl = {
           isMut: true,
           isSigner: true,
         },
+        {
+          name: "creatorVault",
+          isMut: true,
+          isSigner: false,
+        },
         {
           name: "platform",
           isMut: true,
@@ -73,20 +86,10 @@ export const idl = {
           type: "u8",
         },
         {
-          name: "rentalEnabled",
-          type: "bool",
-        },
-        {
-          name: "rentalPrice",
-          type: "u64",
-        },
-        {
-          name: "rentalDuration",
-          type: "i64",
-        },
-        {
-          name: "subscriptionTier",
-          type: "u8",
+          name: "launch",
+          type: {
+            defined: "ContentLaunchOptions",
+          },
         },
       ],
     },
@@ -103,6 +106,11 @@ export const idl = {
           isMut: true,
           isSigner: true,
         },
+        {
+          name: "platform",
+          isMut: false,
+          isSigner: false,
+        },
         {
           name: "systemProgram",
           isMut: false,
@@ -137,7 +145,7 @@ export const idl = {
       ],
     },
     {
-      name: "purchaseContent",
+      name: "setDecliningPrice",
       accounts: [
         {
           name: "content",
@@ -145,35 +153,51 @@ export const idl = {
           isSigner: false,
         },
         {
-          name: "buyer",
-          isMut: true,
+          name: "creator",
+          isMut: false,
           isSigner: true,
         },
         {
-          name: "creator",
-          isMut: true,
+          name: "platform",
+          isMut: false,
           isSigner: false,
         },
+      ],
+      args: [
         {
-          name: "platform",
-          isMut: true,
-          isSigner: false,
+          name: "decliningPrice",
+          type: { option: { defined: "DecliningPrice" } },
         },
+      ],
+    },
+    {
+      name: "setRefundWindow",
+      accounts: [
         {
-          name: "purchase",
+          name: "content",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "systemProgram",
+          name: "creator",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "platform",
           isMut: false,
           isSigner: false,
         },
       ],
-      args: [],
+      args: [
+        {
+          name: "refundWindow",
+          type: { option: "i64" },
+        },
+      ],
     },
     {
-      name: "rentContent",
+      name: "setUsdPricing",
       accounts: [
         {
           name: "content",
@@ -181,178 +205,2776 @@ export const idl = {
           isSigner: false,
         },
         {
-          name: "renter",
-          isMut: true,
+          name: "creator",
+          isMut: false,
           isSigner: true,
         },
         {
-          name: "creator",
+          name: "platform",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "priceFeed",
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+      ],
+      args: [
+        {
+          name: "usdPricing",
+          type: { option: { defined: "UsdPricing" } },
+        },
+      ],
+    },
+    {
+      name: "setPaymentMint",
+      accounts: [
+        {
+          name: "content",
           isMut: true,
           isSigner: false,
         },
+        {
+          name: "creator",
+          isMut: false,
+          isSigner: true,
+        },
         {
           name: "platform",
-          isMut: true,
+          isMut: false,
           isSigner: false,
         },
+      ],
+      args: [
         {
-          name: "rental",
+          name: "paymentMint",
+          type: { option: "publicKey" },
+        },
+      ],
+    },
+    {
+      name: "setRevenueSplits",
+      accounts: [
+        {
+          name: "content",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "systemProgram",
+          name: "creator",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "platform",
           isMut: false,
           isSigner: false,
         },
       ],
-      args: [],
+      args: [
+        {
+          name: "revenueSplits",
+          type: { vec: { defined: "RevenueSplit" } },
+        },
+        {
+          name: "lockAfterFirstSale",
+          type: "bool",
+        },
+      ],
     },
     {
-      name: "subscribe",
+      name: "createNftCollection",
       accounts: [
         {
-          name: "subscriber",
+          name: "creator",
           isMut: true,
           isSigner: true,
         },
         {
-          name: "platform",
+          name: "content",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "subscription",
+          name: "collectionMint",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "collectionTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "collectionMetadata",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "collectionMasterEdition",
           isMut: true,
           isSigner: false,
         },
+        {
+          name: "platform",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenMetadataProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "associatedTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
         {
           name: "systemProgram",
           isMut: false,
           isSigner: false,
         },
+        {
+          name: "rent",
+          isMut: false,
+          isSigner: false,
+        },
       ],
       args: [
         {
-          name: "tier",
-          type: "u8",
+          name: "name",
+          type: "string",
+        },
+        {
+          name: "symbol",
+          type: "string",
+        },
+        {
+          name: "uri",
+          type: "string",
         },
       ],
     },
     {
-      name: "resellContent",
+      name: "mintPurchaseNft",
       accounts: [
+        {
+          name: "buyer",
+          isMut: true,
+          isSigner: true,
+        },
         {
           name: "content",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "purchase",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "seller",
+          name: "nftMint",
           isMut: true,
-          isSigner: true,
+          isSigner: false,
         },
         {
-          name: "buyer",
+          name: "buyerNftAccount",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "creator",
+          name: "nftMetadata",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "platform",
+          name: "nftMasterEdition",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "sellerPurchase",
+          name: "collectionMint",
           isMut: false,
           isSigner: false,
         },
         {
-          name: "buyerPurchase",
+          name: "collectionMetadata",
           isMut: true,
           isSigner: false,
         },
+        {
+          name: "collectionMasterEdition",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "platform",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenMetadataProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "associatedTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
         {
           name: "systemProgram",
           isMut: false,
           isSigner: false,
         },
-      ],
-      args: [
         {
-          name: "price",
-          type: "u64",
+          name: "rent",
+          isMut: false,
+          isSigner: false,
         },
       ],
-    },
-  ],
-  accounts: [
-    {
-      name: "Platform",
-      type: {
-        kind: "struct",
-        fields: [
-          {
-            name: "authority",
-            type: "publicKey",
-          },
-          {
-            name: "platformFee",
-            type: "u64",
-          },
-          {
-            name: "totalContentCount",
-            type: "u64",
-          },
-          {
-            name: "totalSalesVolume",
-            type: "u64",
-          },
-        ],
-      },
+      args: [],
     },
     {
-      name: "Content",
-      type: {
-        kind: "struct",
-        fields: [
-          {
-            name: "id",
-            type: "string",
-          },
-          {
-            name: "creator",
-            type: "publicKey",
-          },
-          {
-            name: "arweaveId",
+      name: "purchaseContent",
+      accounts: [
+        {
+          name: "content",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "buyer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "creatorVault",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "platform",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "purchase",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "buyerTokenAccount",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "creatorVaultTokenAccount",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "platformTokenAccount",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "priceFeed",
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+      ],
+      args: [
+        {
+          name: "maxPrice",
+          type: { option: "u64" },
+        },
+      ],
+    },
+    {
+      name: "requestRefund",
+      accounts: [
+        {
+          name: "buyer",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "purchase",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "platform",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "approveRefund",
+      accounts: [
+        {
+          name: "creator",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "content",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "buyer",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "purchase",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "platform",
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "rejectRefund",
+      accounts: [
+        {
+          name: "creator",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "content",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "purchase",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "platform",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "escalateRefund",
+      accounts: [
+        {
+          name: "buyer",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "purchase",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "platform",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "resolveDispute",
+      accounts: [
+        {
+          name: "platform",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "buyer",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "content",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "purchase",
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "refund",
+          type: "bool",
+        },
+      ],
+    },
+    {
+      name: "releaseEscrow",
+      accounts: [
+        {
+          name: "content",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "purchase",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "creatorVault",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "platform",
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "rentContent",
+      accounts: [
+        {
+          name: "content",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "renter",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "creatorVault",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "platform",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "rental",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "renterTokenAccount",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "creatorVaultTokenAccount",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "platformTokenAccount",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "priceFeed",
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+      ],
+      args: [
+        {
+          name: "maxPrice",
+          type: { option: "u64" },
+        },
+      ],
+    },
+    {
+      name: "extendRental",
+      accounts: [
+        {
+          name: "content",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "renter",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "creatorVault",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "platform",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "rental",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "renterTokenAccount",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "creatorVaultTokenAccount",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "platformTokenAccount",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "priceFeed",
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "passMint",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "token2022Program",
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+      ],
+      args: [
+        {
+          name: "periods",
+          type: "u32",
+        },
+        {
+          name: "maxPrice",
+          type: { option: "u64" },
+        },
+      ],
+    },
+    {
+      name: "closeRental",
+      accounts: [
+        {
+          name: "caller",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "renter",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "rental",
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "issueRentalPass",
+      accounts: [
+        {
+          name: "renter",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "content",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "rental",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "passMint",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "renterPassAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "platform",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "token2022Program",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "associatedTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "burnExpiredRental",
+      accounts: [
+        {
+          name: "renter",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "content",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "rental",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "passMint",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "renterPassAccount",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "token2022Program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "createSubscriptionTier",
+      accounts: [
+        {
+          name: "tierConfig",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "platform",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "authority",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "tier",
+          type: "u8",
+        },
+        {
+          name: "price",
+          type: "u64",
+        },
+        {
+          name: "period",
+          type: "i64",
+        },
+        {
+          name: "name",
+          type: "string",
+        },
+        {
+          name: "paymentMint",
+          type: { option: "publicKey" },
+        },
+      ],
+    },
+    {
+      name: "updateSubscriptionTier",
+      accounts: [
+        {
+          name: "tierConfig",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "platform",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+      ],
+      args: [
+        {
+          name: "price",
+          type: { option: "u64" },
+        },
+        {
+          name: "period",
+          type: { option: "i64" },
+        },
+        {
+          name: "name",
+          type: { option: "string" },
+        },
+      ],
+    },
+    {
+      name: "retireSubscriptionTier",
+      accounts: [
+        {
+          name: "tierConfig",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "platform",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "subscribe",
+      accounts: [
+        {
+          name: "subscriber",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "platform",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tierConfig",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "revenueEpoch",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenRevenuePool",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "subscription",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "subscriberTokenAccount",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "platformTokenAccount",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+      ],
+      args: [
+        {
+          name: "tier",
+          type: "u8",
+        },
+      ],
+    },
+    {
+      name: "renewSubscription",
+      accounts: [
+        {
+          name: "subscriber",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "platform",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tierConfig",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "revenueEpoch",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenRevenuePool",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "subscription",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "subscriberTokenAccount",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "platformTokenAccount",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "changeSubscriptionTier",
+      accounts: [
+        {
+          name: "subscriber",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "platform",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "currentTierConfig",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "newTierConfig",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "revenueEpoch",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenRevenuePool",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "subscription",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "subscriberTokenAccount",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "platformTokenAccount",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+      ],
+      args: [
+        {
+          name: "newTier",
+          type: "u8",
+        },
+      ],
+    },
+    {
+      name: "cancelSubscription",
+      accounts: [
+        {
+          name: "subscriber",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "subscription",
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "accessContentViaSubscription",
+      accounts: [
+        {
+          name: "content",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "subscriber",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "platform",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "subscription",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "accessReceipt",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "epochAccess",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "revenueEpoch",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "creatorEpochStats",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "claimSubscriptionRevenue",
+      accounts: [
+        {
+          name: "creator",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "platform",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "revenueEpoch",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "creatorEpochStats",
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "epoch",
+          type: "u64",
+        },
+      ],
+    },
+    {
+      name: "claimEarnings",
+      accounts: [
+        {
+          name: "creator",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "creatorVault",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "platform",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "claimTokenSubscriptionRevenue",
+      accounts: [
+        {
+          name: "creator",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "platform",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "revenueEpoch",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "creatorEpochStats",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "paymentMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenRevenuePool",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenRevenueClaim",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "platformTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "creatorTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "epoch",
+          type: "u64",
+        },
+      ],
+    },
+    {
+      name: "sweepSubscriptionRevenue",
+      accounts: [
+        {
+          name: "platform",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "revenueEpoch",
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "epoch",
+          type: "u64",
+        },
+      ],
+    },
+    {
+      name: "sweepTokenSubscriptionRevenue",
+      accounts: [
+        {
+          name: "platform",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "revenueEpoch",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "paymentMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenRevenuePool",
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "epoch",
+          type: "u64",
+        },
+      ],
+    },
+    {
+      name: "claimTokenEarnings",
+      accounts: [
+        {
+          name: "creator",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "creatorVault",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "paymentMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "vaultTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "creatorTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "platform",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "verifyAccess",
+      accounts: [
+        {
+          name: "content",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "user",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "purchase",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "rental",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "subscription",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "nftTokenAccount",
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+      ],
+      args: [],
+      returns: {
+        defined: "AccessInfo",
+      },
+    },
+    {
+      name: "resellContent",
+      accounts: [
+        {
+          name: "content",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "seller",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "buyer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "creatorVault",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "platform",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "sellerPurchase",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "sellerListing",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "buyerPurchase",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "buyerTokenAccount",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "creatorVaultTokenAccount",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "sellerTokenAccount",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "platformTokenAccount",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+      ],
+      args: [
+        {
+          name: "price",
+          type: "u64",
+        },
+      ],
+    },
+    {
+      name: "listForResale",
+      accounts: [
+        {
+          name: "content",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "seller",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "platform",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "sellerPurchase",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "listing",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "price",
+          type: "u64",
+        },
+      ],
+    },
+    {
+      name: "buyListing",
+      accounts: [
+        {
+          name: "content",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "buyer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "seller",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "creatorVault",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "platform",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "listing",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "sellerPurchase",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "buyerPurchase",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "buyerTokenAccount",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "creatorVaultTokenAccount",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "sellerTokenAccount",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "platformTokenAccount",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "nftMint",
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "nftMasterEdition",
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "sellerNftAccount",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "buyerNftAccount",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "tokenMetadataProgram",
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+      ],
+      args: [
+        {
+          name: "price",
+          type: "u64",
+        },
+      ],
+    },
+    {
+      name: "cancelListing",
+      accounts: [
+        {
+          name: "seller",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "listing",
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "createAuction",
+      accounts: [
+        {
+          name: "content",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "creator",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "platform",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "auction",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "reservePrice",
+          type: "u64",
+        },
+        {
+          name: "startTime",
+          type: "i64",
+        },
+        {
+          name: "endTime",
+          type: "i64",
+        },
+        {
+          name: "minIncrement",
+          type: "u64",
+        },
+        {
+          name: "extensionWindow",
+          type: "i64",
+        },
+      ],
+    },
+    {
+      name: "placeBid",
+      accounts: [
+        {
+          name: "bidder",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "previousBidder",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "platform",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "auction",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "bidderPurchase",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "amount",
+          type: "u64",
+        },
+      ],
+    },
+    {
+      name: "settleAuction",
+      accounts: [
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "content",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "winner",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "creator",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "creatorVault",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "platform",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "auction",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "winnerPurchase",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "cancelAuction",
+      accounts: [
+        {
+          name: "creator",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "content",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "auction",
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "makeOffer",
+      accounts: [
+        {
+          name: "content",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "bidder",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "platform",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "offer",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "amount",
+          type: "u64",
+        },
+        {
+          name: "expiresAt",
+          type: "i64",
+        },
+      ],
+    },
+    {
+      name: "cancelOffer",
+      accounts: [
+        {
+          name: "bidder",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "offer",
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "refundExpiredOffer",
+      accounts: [
+        {
+          name: "bidder",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "offer",
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "acceptOffer",
+      accounts: [
+        {
+          name: "content",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "seller",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "bidder",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "creatorVault",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "platform",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "offer",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "sellerPurchase",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "sellerListing",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "buyerPurchase",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "updatePlatformFee",
+      accounts: [
+        {
+          name: "platform",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+      ],
+      args: [
+        {
+          name: "platformFeeBps",
+          type: "u16",
+        },
+        {
+          name: "minPlatformFee",
+          type: { option: "u64" },
+        },
+        {
+          name: "maxPlatformFee",
+          type: { option: "u64" },
+        },
+      ],
+    },
+    {
+      name: "setAcceptedMint",
+      accounts: [
+        {
+          name: "platform",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "paymentMint",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "active",
+          type: "bool",
+        },
+      ],
+    },
+    {
+      name: "setPriceOracle",
+      accounts: [
+        {
+          name: "platform",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "priceFeed",
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+      ],
+      args: [
+        {
+          name: "priceOracle",
+          type: { option: { defined: "PriceOracle" } },
+        },
+      ],
+    },
+    {
+      name: "proposeAuthority",
+      accounts: [
+        {
+          name: "platform",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+      ],
+      args: [
+        {
+          name: "newAuthority",
+          type: "publicKey",
+        },
+      ],
+    },
+    {
+      name: "acceptAuthority",
+      accounts: [
+        {
+          name: "platform",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "pendingAuthority",
+          isMut: false,
+          isSigner: true,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "cancelAuthorityTransfer",
+      accounts: [
+        {
+          name: "platform",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "setPause",
+      accounts: [
+        {
+          name: "platform",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+      ],
+      args: [
+        {
+          name: "paused",
+          type: "u32",
+        },
+      ],
+    },
+    {
+      name: "withdrawPlatformFees",
+      accounts: [
+        {
+          name: "platform",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "destination",
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "amount",
+          type: "u64",
+        },
+      ],
+    },
+    {
+      name: "withdrawPlatformTokenFees",
+      accounts: [
+        {
+          name: "platform",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "paymentMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "platformTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "destination",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "amount",
+          type: "u64",
+        },
+      ],
+    },
+  ],
+  accounts: [
+    {
+      name: "Platform",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "authority",
+            type: "publicKey",
+          },
+          {
+            name: "pendingAuthority",
+            type: { option: "publicKey" },
+          },
+          {
+            name: "platformFeeBps",
+            type: "u16",
+          },
+          {
+            name: "minPlatformFee",
+            type: { option: "u64" },
+          },
+          {
+            name: "maxPlatformFee",
+            type: { option: "u64" },
+          },
+          {
+            name: "totalContentCount",
+            type: "u64",
+          },
+          {
+            name: "totalSalesVolume",
+            type: "u64",
+          },
+          {
+            name: "totalWithdrawn",
+            type: "u64",
+          },
+          {
+            name: "paused",
+            type: "u32",
+          },
+          {
+            name: "subscriptionPoolBalance",
+            type: "u64",
+          },
+          {
+            name: "priceOracle",
+            type: { option: { defined: "PriceOracle" } },
+          },
+          {
+            name: "acceptedMints",
+            type: { vec: { defined: "AcceptedMint" } },
+          },
+        ],
+      },
+    },
+    {
+      name: "Content",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "id",
+            type: "string",
+          },
+          {
+            name: "creator",
+            type: "publicKey",
+          },
+          {
+            name: "arweaveId",
+            type: "string",
+          },
+          {
+            name: "price",
+            type: "u64",
+          },
+          {
+            name: "royaltyPercentage",
+            type: "u8",
+          },
+          {
+            name: "salesCount",
+            type: "u64",
+          },
+          {
+            name: "active",
+            type: "bool",
+          },
+          {
+            name: "createdAt",
+            type: "i64",
+          },
+          {
+            name: "rentalEnabled",
+            type: "bool",
+          },
+          {
+            name: "rentalPrice",
+            type: "u64",
+          },
+          {
+            name: "rentalDuration",
+            type: "i64",
+          },
+          {
+            name: "subscriptionTier",
+            type: "u8",
+          },
+          {
+            name: "decliningPrice",
+            type: { option: { defined: "DecliningPrice" } },
+          },
+          {
+            name: "maxSupply",
+            type: { option: "u64" },
+          },
+          {
+            name: "reservedEditions",
+            type: "u64",
+          },
+          {
+            name: "revenueSplits",
+            type: { vec: { defined: "RevenueSplit" } },
+          },
+          {
+            name: "revenueSplitsLocked",
+            type: "bool",
+          },
+          {
+            name: "refundWindow",
+            type: { option: "i64" },
+          },
+          {
+            name: "paymentMint",
+            type: { option: "publicKey" },
+          },
+          {
+            name: "usdPricing",
+            type: { option: { defined: "UsdPricing" } },
+          },
+          {
+            name: "nftCollection",
+            type: { option: "publicKey" },
+          },
+        ],
+      },
+    },
+    {
+      name: "Purchase",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "buyer",
+            type: "publicKey",
+          },
+          {
+            name: "content",
+            type: "publicKey",
+          },
+          {
+            name: "price",
+            type: "u64",
+          },
+          {
+            name: "timestamp",
+            type: "i64",
+          },
+          {
+            name: "resaleRights",
+            type: "bool",
+          },
+          {
+            name: "purchaseType",
+            type: {
+              defined: "PurchaseType",
+            },
+          },
+          {
+            name: "expiration",
+            type: { option: "i64" },
+          },
+          {
+            name: "acquiredFrom",
+            type: { option: "publicKey" },
+          },
+          {
+            name: "transferredTo",
+            type: { option: "publicKey" },
+          },
+          {
+            name: "editionNumber",
+            type: { option: "u64" },
+          },
+          {
+            name: "refundEscrow",
+            type: { option: { defined: "RefundEscrow" } },
+          },
+          {
+            name: "nftMint",
+            type: { option: "publicKey" },
+          },
+          {
+            name: "passMint",
+            type: { option: "publicKey" },
+          },
+        ],
+      },
+    },
+    {
+      name: "Listing",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "seller",
+            type: "publicKey",
+          },
+          {
+            name: "content",
+            type: "publicKey",
+          },
+          {
+            name: "purchase",
+            type: "publicKey",
+          },
+          {
+            name: "price",
+            type: "u64",
+          },
+          {
+            name: "createdAt",
+            type: "i64",
+          },
+          {
+            name: "paymentMint",
+            type: { option: "publicKey" },
+          },
+          {
+            name: "acquiredAt",
+            type: "i64",
+          },
+        ],
+      },
+    },
+    {
+      name: "Auction",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "content",
+            type: "publicKey",
+          },
+          {
+            name: "creator",
+            type: "publicKey",
+          },
+          {
+            name: "reservePrice",
+            type: "u64",
+          },
+          {
+            name: "startTime",
+            type: "i64",
+          },
+          {
+            name: "endTime",
+            type: "i64",
+          },
+          {
+            name: "minIncrement",
+            type: "u64",
+          },
+          {
+            name: "extensionWindow",
+            type: "i64",
+          },
+          {
+            name: "highestBidder",
+            type: { option: "publicKey" },
+          },
+          {
+            name: "highestBid",
+            type: "u64",
+          },
+          {
+            name: "bidCount",
+            type: "u64",
+          },
+        ],
+      },
+    },
+    {
+      name: "Offer",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "bidder",
+            type: "publicKey",
+          },
+          {
+            name: "content",
+            type: "publicKey",
+          },
+          {
+            name: "amount",
+            type: "u64",
+          },
+          {
+            name: "createdAt",
+            type: "i64",
+          },
+          {
+            name: "expiresAt",
+            type: "i64",
+          },
+        ],
+      },
+    },
+    {
+      name: "Subscription",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "subscriber",
+            type: "publicKey",
+          },
+          {
+            name: "tier",
+            type: "u8",
+          },
+          {
+            name: "startTime",
+            type: "i64",
+          },
+          {
+            name: "expirationTime",
+            type: "i64",
+          },
+          {
+            name: "active",
+            type: "bool",
+          },
+          {
+            name: "paidPrice",
+            type: "u64",
+          },
+          {
+            name: "paidPeriod",
+            type: "i64",
+          },
+        ],
+      },
+    },
+    {
+      name: "SubscriptionTierConfig",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "tier",
+            type: "u8",
+          },
+          {
+            name: "price",
+            type: "u64",
+          },
+          {
+            name: "period",
+            type: "i64",
+          },
+          {
+            name: "active",
+            type: "bool",
+          },
+          {
+            name: "name",
             type: "string",
           },
           {
-            name: "price",
+            name: "paymentMint",
+            type: { option: "publicKey" },
+          },
+        ],
+      },
+    },
+    {
+      name: "AccessReceipt",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "subscriber",
+            type: "publicKey",
+          },
+          {
+            name: "content",
+            type: "publicKey",
+          },
+          {
+            name: "tier",
+            type: "u8",
+          },
+          {
+            name: "accessCount",
+            type: "u64",
+          },
+          {
+            name: "firstAccessedAt",
+            type: "i64",
+          },
+          {
+            name: "lastAccessedAt",
+            type: "i64",
+          },
+        ],
+      },
+    },
+    {
+      name: "EpochAccess",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "subscriber",
+            type: "publicKey",
+          },
+          {
+            name: "creator",
+            type: "publicKey",
+          },
+          {
+            name: "epoch",
+            type: "u64",
+          },
+        ],
+      },
+    },
+    {
+      name: "RevenueEpoch",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "epoch",
+            type: "u64",
+          },
+          {
+            name: "totalRevenue",
+            type: "u64",
+          },
+          {
+            name: "totalAccesses",
+            type: "u64",
+          },
+          {
+            name: "totalClaimed",
+            type: "u64",
+          },
+          {
+            name: "claimedAccesses",
+            type: "u64",
+          },
+          {
+            name: "totalSwept",
+            type: "u64",
+          },
+        ],
+      },
+    },
+    {
+      name: "CreatorEpochStats",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "creator",
+            type: "publicKey",
+          },
+          {
+            name: "epoch",
+            type: "u64",
+          },
+          {
+            name: "accessCount",
+            type: "u64",
+          },
+          {
+            name: "claimed",
+            type: "bool",
+          },
+          {
+            name: "amountClaimed",
+            type: "u64",
+          },
+        ],
+      },
+    },
+    {
+      name: "TokenRevenuePool",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "epoch",
+            type: "u64",
+          },
+          {
+            name: "mint",
+            type: "publicKey",
+          },
+          {
+            name: "totalRevenue",
+            type: "u64",
+          },
+          {
+            name: "totalClaimed",
+            type: "u64",
+          },
+          {
+            name: "claimedAccesses",
+            type: "u64",
+          },
+          {
+            name: "totalSwept",
+            type: "u64",
+          },
+        ],
+      },
+    },
+    {
+      name: "TokenRevenueClaim",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "creator",
+            type: "publicKey",
+          },
+          {
+            name: "epoch",
+            type: "u64",
+          },
+          {
+            name: "mint",
+            type: "publicKey",
+          },
+          {
+            name: "amount",
             type: "u64",
           },
+        ],
+      },
+    },
+    {
+      name: "CreatorVault",
+      type: {
+        kind: "struct",
+        fields: [
           {
-            name: "royaltyPercentage",
-            type: "u8",
+            name: "creator",
+            type: "publicKey",
           },
           {
-            name: "salesCount",
+            name: "totalEarned",
+            type: "u64",
+          },
+          {
+            name: "totalClaimed",
             type: "u64",
           },
+          {
+            name: "tokenEarnings",
+            type: { vec: { defined: "TokenEarnings" } },
+          },
+        ],
+      },
+    },
+  ],
+  types: [
+    {
+      name: "AcceptedMint",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "mint",
+            type: "publicKey",
+          },
           {
             name: "active",
             type: "bool",
           },
           {
-            name: "createdAt",
-            type: "i64",
+            name: "subscriptionPoolBalance",
+            type: "u64",
+          },
+        ],
+      },
+    },
+    {
+      name: "RevenueSplit",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "recipient",
+            type: "publicKey",
           },
+          {
+            name: "shareBps",
+            type: "u16",
+          },
+        ],
+      },
+    },
+    {
+      name: "ContentLaunchOptions",
+      type: {
+        kind: "struct",
+        fields: [
           {
             name: "rentalEnabled",
             type: "bool",
@@ -369,106 +2991,1892 @@ export const idl = {
             name: "subscriptionTier",
             type: "u8",
           },
+          {
+            name: "maxSupply",
+            type: { option: "u64" },
+          },
+        ],
+      },
+    },
+    {
+      name: "DecliningPrice",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "startPrice",
+            type: "u64",
+          },
+          {
+            name: "floorPrice",
+            type: "u64",
+          },
+          {
+            name: "startTime",
+            type: "i64",
+          },
+          {
+            name: "endTime",
+            type: "i64",
+          },
+          {
+            name: "stepInterval",
+            type: "i64",
+          },
+        ],
+      },
+    },
+    {
+      name: "UsdPricing",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "priceUsdCents",
+            type: "u64",
+          },
+          {
+            name: "rentalPriceUsdCents",
+            type: "u64",
+          },
+        ],
+      },
+    },
+    {
+      name: "PriceOracle",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "programId",
+            type: "publicKey",
+          },
+          {
+            name: "solUsdFeed",
+            type: "publicKey",
+          },
+        ],
+      },
+    },
+    {
+      name: "RefundEscrow",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "platformFee",
+            type: "u64",
+          },
+          {
+            name: "releaseAfter",
+            type: "i64",
+          },
+          {
+            name: "status",
+            type: {
+              defined: "RefundStatus",
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "AccessInfo",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "kind",
+            type: {
+              defined: "AccessKind",
+            },
+          },
+          {
+            name: "expiration",
+            type: { option: "i64" },
+          },
+        ],
+      },
+    },
+    {
+      name: "TokenEarnings",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "mint",
+            type: "publicKey",
+          },
+          {
+            name: "totalEarned",
+            type: "u64",
+          },
+          {
+            name: "totalClaimed",
+            type: "u64",
+          },
+        ],
+      },
+    },
+    {
+      name: "PurchaseType",
+      type: {
+        kind: "enum",
+        variants: [
+          {
+            name: "FullPurchase",
+          },
+          {
+            name: "Rental",
+          },
+        ],
+      },
+    },
+    {
+      name: "RefundStatus",
+      type: {
+        kind: "enum",
+        variants: [
+          {
+            name: "Open",
+          },
+          {
+            name: "Requested",
+          },
+          {
+            name: "Rejected",
+          },
+          {
+            name: "Disputed",
+          },
+          {
+            name: "Upheld",
+          },
+        ],
+      },
+    },
+    {
+      name: "AccessKind",
+      type: {
+        kind: "enum",
+        variants: [
+          {
+            name: "Purchase",
+          },
+          {
+            name: "Rental",
+          },
+          {
+            name: "Subscription",
+          },
         ],
       },
     },
+  ],
+  events: [
+    {
+      name: "PlatformInitialized",
+      fields: [
+        {
+          name: "authority",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "platformFeeBps",
+          type: "u16",
+          index: false,
+        },
+        {
+          name: "minPlatformFee",
+          type: { option: "u64" },
+          index: false,
+        },
+        {
+          name: "maxPlatformFee",
+          type: { option: "u64" },
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "PlatformFeeUpdated",
+      fields: [
+        {
+          name: "authority",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "previousFeeBps",
+          type: "u16",
+          index: false,
+        },
+        {
+          name: "platformFeeBps",
+          type: "u16",
+          index: false,
+        },
+        {
+          name: "minPlatformFee",
+          type: { option: "u64" },
+          index: false,
+        },
+        {
+          name: "maxPlatformFee",
+          type: { option: "u64" },
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "AuthorityTransferProposed",
+      fields: [
+        {
+          name: "authority",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "pendingAuthority",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "AuthorityTransferAccepted",
+      fields: [
+        {
+          name: "previousAuthority",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "authority",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "AuthorityTransferCancelled",
+      fields: [
+        {
+          name: "authority",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "pendingAuthority",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "PlatformPauseUpdated",
+      fields: [
+        {
+          name: "authority",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "previousPaused",
+          type: "u32",
+          index: false,
+        },
+        {
+          name: "paused",
+          type: "u32",
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "PlatformTokenFeesWithdrawn",
+      fields: [
+        {
+          name: "authority",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "destination",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "mint",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "amount",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "AcceptedMintUpdated",
+      fields: [
+        {
+          name: "authority",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "mint",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "active",
+          type: "bool",
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "PriceOracleUpdated",
+      fields: [
+        {
+          name: "authority",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "priceOracle",
+          type: { option: { defined: "PriceOracle" } },
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "PlatformFeesWithdrawn",
+      fields: [
+        {
+          name: "authority",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "destination",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "amount",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "totalWithdrawn",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "ContentCreated",
+      fields: [
+        {
+          name: "contentId",
+          type: "string",
+          index: false,
+        },
+        {
+          name: "creator",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "price",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "royaltyPercentage",
+          type: "u8",
+          index: false,
+        },
+        {
+          name: "rentalEnabled",
+          type: "bool",
+          index: false,
+        },
+        {
+          name: "subscriptionTier",
+          type: "u8",
+          index: false,
+        },
+        {
+          name: "maxSupply",
+          type: { option: "u64" },
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "ContentUpdated",
+      fields: [
+        {
+          name: "contentId",
+          type: "string",
+          index: false,
+        },
+        {
+          name: "creator",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "price",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "active",
+          type: "bool",
+          index: false,
+        },
+        {
+          name: "rentalEnabled",
+          type: "bool",
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "DecliningPriceUpdated",
+      fields: [
+        {
+          name: "contentId",
+          type: "string",
+          index: false,
+        },
+        {
+          name: "creator",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "decliningPrice",
+          type: { option: { defined: "DecliningPrice" } },
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "UsdPricingUpdated",
+      fields: [
+        {
+          name: "contentId",
+          type: "string",
+          index: false,
+        },
+        {
+          name: "creator",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "usdPricing",
+          type: { option: { defined: "UsdPricing" } },
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "PaymentMintUpdated",
+      fields: [
+        {
+          name: "contentId",
+          type: "string",
+          index: false,
+        },
+        {
+          name: "creator",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "paymentMint",
+          type: { option: "publicKey" },
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "RevenueSplitsUpdated",
+      fields: [
+        {
+          name: "contentId",
+          type: "string",
+          index: false,
+        },
+        {
+          name: "creator",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "revenueSplits",
+          type: { vec: { defined: "RevenueSplit" } },
+          index: false,
+        },
+        {
+          name: "locked",
+          type: "bool",
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "RefundWindowUpdated",
+      fields: [
+        {
+          name: "contentId",
+          type: "string",
+          index: false,
+        },
+        {
+          name: "creator",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "refundWindow",
+          type: { option: "i64" },
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "NftCollectionCreated",
+      fields: [
+        {
+          name: "contentId",
+          type: "string",
+          index: false,
+        },
+        {
+          name: "creator",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "collectionMint",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "name",
+          type: "string",
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "PurchaseNftMinted",
+      fields: [
+        {
+          name: "contentId",
+          type: "string",
+          index: false,
+        },
+        {
+          name: "purchase",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "buyer",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "nftMint",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "editionNumber",
+          type: { option: "u64" },
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "ContentPurchased",
+      fields: [
+        {
+          name: "contentId",
+          type: "string",
+          index: false,
+        },
+        {
+          name: "buyer",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "creator",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "price",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "platformFee",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "editionNumber",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "maxSupply",
+          type: { option: "u64" },
+          index: false,
+        },
+        {
+          name: "refundDeadline",
+          type: { option: "i64" },
+          index: false,
+        },
+        {
+          name: "paymentMint",
+          type: { option: "publicKey" },
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "RefundRequested",
+      fields: [
+        {
+          name: "purchase",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "buyer",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "content",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "price",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "respondBy",
+          type: "i64",
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "RefundApproved",
+      fields: [
+        {
+          name: "purchase",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "buyer",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "content",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "approvedBy",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "price",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "RefundRejected",
+      fields: [
+        {
+          name: "purchase",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "buyer",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "content",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "escalateBy",
+          type: "i64",
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "RefundEscalated",
+      fields: [
+        {
+          name: "purchase",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "buyer",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "content",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "arbiter",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "DisputeResolved",
+      fields: [
+        {
+          name: "purchase",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "buyer",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "arbiter",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "refunded",
+          type: "bool",
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "EscrowReleased",
+      fields: [
+        {
+          name: "purchase",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "buyer",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "creator",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "price",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "platformFee",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "ContentRented",
+      fields: [
+        {
+          name: "contentId",
+          type: "string",
+          index: false,
+        },
+        {
+          name: "renter",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "creator",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "price",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "platformFee",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "expiration",
+          type: "i64",
+          index: false,
+        },
+        {
+          name: "paymentMint",
+          type: { option: "publicKey" },
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "RentalExtended",
+      fields: [
+        {
+          name: "contentId",
+          type: "string",
+          index: false,
+        },
+        {
+          name: "renter",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "periods",
+          type: "u32",
+          index: false,
+        },
+        {
+          name: "price",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "platformFee",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "previousExpiration",
+          type: "i64",
+          index: false,
+        },
+        {
+          name: "expiration",
+          type: "i64",
+          index: false,
+        },
+        {
+          name: "paymentMint",
+          type: { option: "publicKey" },
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "RentalClosed",
+      fields: [
+        {
+          name: "rental",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "renter",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "content",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "expired",
+          type: "bool",
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "RentalPassIssued",
+      fields: [
+        {
+          name: "contentId",
+          type: "string",
+          index: false,
+        },
+        {
+          name: "rental",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "renter",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "passMint",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "expiration",
+          type: "i64",
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "RentalPassBurned",
+      fields: [
+        {
+          name: "contentId",
+          type: "string",
+          index: false,
+        },
+        {
+          name: "rental",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "renter",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "passMint",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "expiration",
+          type: "i64",
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "ContentResold",
+      fields: [
+        {
+          name: "contentId",
+          type: "string",
+          index: false,
+        },
+        {
+          name: "seller",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "buyer",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "creator",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "price",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "royaltyAmount",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "platformFee",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "paymentMint",
+          type: { option: "publicKey" },
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "SubscriptionTierCreated",
+      fields: [
+        {
+          name: "tier",
+          type: "u8",
+          index: false,
+        },
+        {
+          name: "price",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "period",
+          type: "i64",
+          index: false,
+        },
+        {
+          name: "name",
+          type: "string",
+          index: false,
+        },
+        {
+          name: "paymentMint",
+          type: { option: "publicKey" },
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "SubscriptionTierUpdated",
+      fields: [
+        {
+          name: "tier",
+          type: "u8",
+          index: false,
+        },
+        {
+          name: "price",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "period",
+          type: "i64",
+          index: false,
+        },
+        {
+          name: "name",
+          type: "string",
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "SubscriptionTierRetired",
+      fields: [
+        {
+          name: "tier",
+          type: "u8",
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "SubscriptionCreated",
+      fields: [
+        {
+          name: "subscriber",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "tier",
+          type: "u8",
+          index: false,
+        },
+        {
+          name: "price",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "platformFee",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "expiration",
+          type: "i64",
+          index: false,
+        },
+        {
+          name: "paymentMint",
+          type: { option: "publicKey" },
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "SubscriptionRenewed",
+      fields: [
+        {
+          name: "subscriber",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "tier",
+          type: "u8",
+          index: false,
+        },
+        {
+          name: "price",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "platformFee",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "expiration",
+          type: "i64",
+          index: false,
+        },
+        {
+          name: "paymentMint",
+          type: { option: "publicKey" },
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "SubscriptionTierChanged",
+      fields: [
+        {
+          name: "subscriber",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "previousTier",
+          type: "u8",
+          index: false,
+        },
+        {
+          name: "tier",
+          type: "u8",
+          index: false,
+        },
+        {
+          name: "price",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "credit",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "platformFee",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "expiration",
+          type: "i64",
+          index: false,
+        },
+        {
+          name: "paymentMint",
+          type: { option: "publicKey" },
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "SubscriptionCancelled",
+      fields: [
+        {
+          name: "subscriber",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "tier",
+          type: "u8",
+          index: false,
+        },
+        {
+          name: "expiration",
+          type: "i64",
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "ContentAccessedViaSubscription",
+      fields: [
+        {
+          name: "contentId",
+          type: "string",
+          index: false,
+        },
+        {
+          name: "content",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "creator",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "subscriber",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "tier",
+          type: "u8",
+          index: false,
+        },
+        {
+          name: "accessCount",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "expiration",
+          type: "i64",
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "SubscriptionRevenueClaimed",
+      fields: [
+        {
+          name: "creator",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "epoch",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "accessCount",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "totalAccesses",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "amount",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "SubscriptionRevenueSwept",
+      fields: [
+        {
+          name: "epoch",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "mint",
+          type: { option: "publicKey" },
+          index: false,
+        },
+        {
+          name: "amount",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "EarningsClaimed",
+      fields: [
+        {
+          name: "creator",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "amount",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "totalEarned",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "totalClaimed",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "TokenEarningsClaimed",
+      fields: [
+        {
+          name: "creator",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "mint",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "amount",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "totalEarned",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "totalClaimed",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "TokenSubscriptionRevenueClaimed",
+      fields: [
+        {
+          name: "creator",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "epoch",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "mint",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "accessCount",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "totalAccesses",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "amount",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "ListingCreated",
+      fields: [
+        {
+          name: "contentId",
+          type: "string",
+          index: false,
+        },
+        {
+          name: "listing",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "seller",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "price",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "paymentMint",
+          type: { option: "publicKey" },
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
+    },
     {
-      name: "Purchase",
-      type: {
-        kind: "struct",
-        fields: [
-          {
-            name: "buyer",
-            type: "publicKey",
-          },
-          {
-            name: "content",
-            type: "publicKey",
-          },
-          {
-            name: "price",
-            type: "u64",
-          },
-          {
-            name: "timestamp",
-            type: "i64",
-          },
-          {
-            name: "resaleRights",
-            type: "bool",
-          },
-          {
-            name: "purchaseType",
-            type: {
-              defined: "PurchaseType",
-            },
-          },
-          {
-            name: "expiration",
-            type: { option: "i64" },
-          },
-        ],
-      },
+      name: "ListingPurchased",
+      fields: [
+        {
+          name: "contentId",
+          type: "string",
+          index: false,
+        },
+        {
+          name: "listing",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "seller",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "buyer",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "creator",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "price",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "royaltyAmount",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "platformFee",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "paymentMint",
+          type: { option: "publicKey" },
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
     },
     {
-      name: "Subscription",
-      type: {
-        kind: "struct",
-        fields: [
-          {
-            name: "subscriber",
-            type: "publicKey",
-          },
-          {
-            name: "tier",
-            type: "u8",
-          },
-          {
-            name: "startTime",
-            type: "i64",
-          },
-          {
-            name: "expirationTime",
-            type: "i64",
-          },
-          {
-            name: "active",
-            type: "bool",
-          },
-        ],
-      },
+      name: "ListingCancelled",
+      fields: [
+        {
+          name: "listing",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "seller",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "content",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
     },
-  ],
-  types: [
     {
-      name: "PurchaseType",
-      type: {
-        kind: "enum",
-        variants: [
-          {
-            name: "FullPurchase",
-          },
-          {
-            name: "Rental",
-          },
-        ],
-      },
+      name: "AuctionCreated",
+      fields: [
+        {
+          name: "contentId",
+          type: "string",
+          index: false,
+        },
+        {
+          name: "auction",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "creator",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "reservePrice",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "startTime",
+          type: "i64",
+          index: false,
+        },
+        {
+          name: "endTime",
+          type: "i64",
+          index: false,
+        },
+        {
+          name: "minIncrement",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
     },
-  ],
-  events: [
     {
-      name: "PlatformInitialized",
+      name: "BidPlaced",
+      fields: [
+        {
+          name: "auction",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "content",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "bidder",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "amount",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "endTime",
+          type: "i64",
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "AuctionSettled",
       fields: [
         {
-          name: "authority",
+          name: "contentId",
+          type: "string",
+          index: false,
+        },
+        {
+          name: "auction",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "winner",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "creator",
           type: "publicKey",
           index: false,
         },
+        {
+          name: "price",
+          type: "u64",
+          index: false,
+        },
         {
           name: "platformFee",
           type: "u64",
           index: false,
         },
+        {
+          name: "editionNumber",
+          type: "u64",
+          index: false,
+        },
         {
           name: "timestamp",
           type: "i64",
@@ -477,7 +4885,7 @@ export const idl = {
       ],
     },
     {
-      name: "ContentCreated",
+      name: "AuctionVoided",
       fields: [
         {
           name: "contentId",
@@ -485,28 +4893,43 @@ export const idl = {
           index: false,
         },
         {
-          name: "creator",
+          name: "auction",
           type: "publicKey",
           index: false,
         },
         {
-          name: "price",
+          name: "winner",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "refund",
           type: "u64",
           index: false,
         },
         {
-          name: "royaltyPercentage",
-          type: "u8",
+          name: "timestamp",
+          type: "i64",
           index: false,
         },
+      ],
+    },
+    {
+      name: "AuctionCancelled",
+      fields: [
         {
-          name: "rentalEnabled",
-          type: "bool",
+          name: "auction",
+          type: "publicKey",
           index: false,
         },
         {
-          name: "subscriptionTier",
-          type: "u8",
+          name: "content",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "creator",
+          type: "publicKey",
           index: false,
         },
         {
@@ -517,7 +4940,7 @@ export const idl = {
       ],
     },
     {
-      name: "ContentUpdated",
+      name: "OfferMade",
       fields: [
         {
           name: "contentId",
@@ -525,23 +4948,23 @@ export const idl = {
           index: false,
         },
         {
-          name: "creator",
+          name: "offer",
           type: "publicKey",
           index: false,
         },
         {
-          name: "price",
-          type: "u64",
+          name: "bidder",
+          type: "publicKey",
           index: false,
         },
         {
-          name: "active",
-          type: "bool",
+          name: "amount",
+          type: "u64",
           index: false,
         },
         {
-          name: "rentalEnabled",
-          type: "bool",
+          name: "expiresAt",
+          type: "i64",
           index: false,
         },
         {
@@ -552,25 +4975,25 @@ export const idl = {
       ],
     },
     {
-      name: "ContentPurchased",
+      name: "OfferCancelled",
       fields: [
         {
-          name: "contentId",
-          type: "string",
+          name: "offer",
+          type: "publicKey",
           index: false,
         },
         {
-          name: "buyer",
+          name: "bidder",
           type: "publicKey",
           index: false,
         },
         {
-          name: "creator",
+          name: "content",
           type: "publicKey",
           index: false,
         },
         {
-          name: "price",
+          name: "amount",
           type: "u64",
           index: false,
         },
@@ -582,33 +5005,28 @@ export const idl = {
       ],
     },
     {
-      name: "ContentRented",
+      name: "OfferRefunded",
       fields: [
         {
-          name: "contentId",
-          type: "string",
+          name: "offer",
+          type: "publicKey",
           index: false,
         },
         {
-          name: "renter",
+          name: "bidder",
           type: "publicKey",
           index: false,
         },
         {
-          name: "creator",
+          name: "content",
           type: "publicKey",
           index: false,
         },
         {
-          name: "price",
+          name: "amount",
           type: "u64",
           index: false,
         },
-        {
-          name: "expiration",
-          type: "i64",
-          index: false,
-        },
         {
           name: "timestamp",
           type: "i64",
@@ -617,20 +5035,25 @@ export const idl = {
       ],
     },
     {
-      name: "ContentResold",
+      name: "OfferAccepted",
       fields: [
         {
           name: "contentId",
           type: "string",
           index: false,
         },
+        {
+          name: "offer",
+          type: "publicKey",
+          index: false,
+        },
         {
           name: "seller",
           type: "publicKey",
           index: false,
         },
         {
-          name: "buyer",
+          name: "bidder",
           type: "publicKey",
           index: false,
         },
@@ -650,35 +5073,10 @@ export const idl = {
           index: false,
         },
         {
-          name: "timestamp",
-          type: "i64",
-          index: false,
-        },
-      ],
-    },
-    {
-      name: "SubscriptionCreated",
-      fields: [
-        {
-          name: "subscriber",
-          type: "publicKey",
-          index: false,
-        },
-        {
-          name: "tier",
-          type: "u8",
-          index: false,
-        },
-        {
-          name: "price",
+          name: "platformFee",
           type: "u64",
           index: false,
         },
-        {
-          name: "expiration",
-          type: "i64",
-          index: false,
-        },
         {
           name: "timestamp",
           type: "i64",
@@ -731,7 +5129,7 @@ export const idl = {
     {
       code: 6008,
       name: "InvalidFeeAmount",
-      msg: "Platform fee must be greater than 0",
+      msg: "Platform fee cannot exceed 10000 basis points",
     },
     {
       code: 6009,
@@ -758,6 +5156,386 @@ export const idl = {
       name: "InvalidSubscriptionTier",
       msg: "Invalid subscription tier",
     },
+    {
+      code: 6014,
+      name: "InvalidWithdrawalAmount",
+      msg: "Withdrawal amount must be greater than 0",
+    },
+    {
+      code: 6015,
+      name: "InsufficientPlatformBalance",
+      msg: "Platform balance above the rent-exempt minimum is too low",
+    },
+    {
+      code: 6016,
+      name: "ArithmeticOverflow",
+      msg: "Arithmetic overflow",
+    },
+    {
+      code: 6017,
+      name: "InvalidFeeBounds",
+      msg: "Minimum platform fee cannot exceed the maximum platform fee",
+    },
+    {
+      code: 6018,
+      name: "PriceBelowFees",
+      msg: "Price is too low to cover royalty and platform fees",
+    },
+    {
+      code: 6019,
+      name: "InvalidPendingAuthority",
+      msg: "Pending authority must differ from the current authority",
+    },
+    {
+      code: 6020,
+      name: "NoPendingAuthority",
+      msg: "No authority transfer is pending",
+    },
+    {
+      code: 6021,
+      name: "PlatformPaused",
+      msg: "This instruction is paused by the platform",
+    },
+    {
+      code: 6022,
+      name: "InvalidSubscriptionPeriod",
+      msg: "Subscription period must be greater than 0",
+    },
+    {
+      code: 6023,
+      name: "InvalidTierName",
+      msg: "Subscription tier name must be between 1 and 32 bytes",
+    },
+    {
+      code: 6024,
+      name: "SubscriptionTierInactive",
+      msg: "Subscription tier is not active",
+    },
+    {
+      code: 6025,
+      name: "SubscriptionNotActive",
+      msg: "Subscription is not active",
+    },
+    {
+      code: 6026,
+      name: "SameSubscriptionTier",
+      msg: "Subscription is already on this tier",
+    },
+    {
+      code: 6027,
+      name: "SubscriptionExpired",
+      msg: "Subscription has expired",
+    },
+    {
+      code: 6028,
+      name: "SubscriptionTierTooLow",
+      msg: "Subscription tier is too low for this content",
+    },
+    {
+      code: 6029,
+      name: "ContentNotInSubscription",
+      msg: "Content is not available through subscriptions",
+    },
+    {
+      code: 6030,
+      name: "RevenueEpochNotClosed",
+      msg: "Revenue epoch has not closed yet",
+    },
+    {
+      code: 6031,
+      name: "RevenueAlreadyClaimed",
+      msg: "Revenue for this epoch has already been claimed",
+    },
+    {
+      code: 6032,
+      name: "RevenueStillClaimable",
+      msg: "Creators can still claim this revenue",
+    },
+    {
+      code: 6033,
+      name: "NoRevenueToSweep",
+      msg: "No unclaimable revenue left to sweep",
+    },
+    {
+      code: 6034,
+      name: "NotPurchased",
+      msg: "Content has not been purchased",
+    },
+    {
+      code: 6035,
+      name: "RentalExpired",
+      msg: "Rental has expired",
+    },
+    {
+      code: 6036,
+      name: "ListingPriceMismatch",
+      msg: "Listing price does not match the expected price",
+    },
+    {
+      code: 6037,
+      name: "PurchaseTransferred",
+      msg: "Purchase has been transferred to a new owner",
+    },
+    {
+      code: 6038,
+      name: "InvalidOfferExpiration",
+      msg: "Offer expiration must be in the future",
+    },
+    {
+      code: 6039,
+      name: "OfferExpired",
+      msg: "Offer has expired",
+    },
+    {
+      code: 6040,
+      name: "OfferNotExpired",
+      msg: "Offer has not expired yet",
+    },
+    {
+      code: 6041,
+      name: "InvalidAuctionTimes",
+      msg: "Auction must end after it starts and in the future",
+    },
+    {
+      code: 6042,
+      name: "InvalidBidIncrement",
+      msg: "Minimum bid increment must be greater than 0",
+    },
+    {
+      code: 6043,
+      name: "AuctionNotStarted",
+      msg: "Auction has not started yet",
+    },
+    {
+      code: 6044,
+      name: "AuctionEnded",
+      msg: "Auction has ended",
+    },
+    {
+      code: 6045,
+      name: "AuctionNotEnded",
+      msg: "Auction has not ended yet",
+    },
+    {
+      code: 6046,
+      name: "BidTooLow",
+      msg: "Bid is below the reserve price or minimum increment",
+    },
+    {
+      code: 6047,
+      name: "AuctionHasBids",
+      msg: "Auction already has bids",
+    },
+    {
+      code: 6048,
+      name: "AuctionHasNoBids",
+      msg: "Auction has no winning bid",
+    },
+    {
+      code: 6049,
+      name: "AlreadyPurchased",
+      msg: "Content has already been purchased",
+    },
+    {
+      code: 6050,
+      name: "InvalidDecliningPrice",
+      msg: "Declining price must fall from a start price to a lower floor within its window",
+    },
+    {
+      code: 6051,
+      name: "InvalidMaxSupply",
+      msg: "Max supply must be greater than 0",
+    },
+    {
+      code: 6052,
+      name: "ContentSoldOut",
+      msg: "Content is sold out",
+    },
+    {
+      code: 6053,
+      name: "InvalidRevenueSplits",
+      msg: "Revenue splits need distinct recipients with non-zero shares summing to 10000 basis points",
+    },
+    {
+      code: 6054,
+      name: "RevenueSplitsLocked",
+      msg: "Revenue splits are locked after the first sale",
+    },
+    {
+      code: 6055,
+      name: "InvalidSplitRecipient",
+      msg: "Revenue split recipient accounts are missing, out of order or not writable",
+    },
+    {
+      code: 6056,
+      name: "NoEarningsToClaim",
+      msg: "No earnings to claim",
+    },
+    {
+      code: 6057,
+      name: "InvalidRefundWindow",
+      msg: "Refund window must be positive",
+    },
+    {
+      code: 6058,
+      name: "PurchaseNotInEscrow",
+      msg: "Purchase has no payment held in escrow",
+    },
+    {
+      code: 6059,
+      name: "PurchaseInEscrow",
+      msg: "Purchase payment is still held in escrow",
+    },
+    {
+      code: 6060,
+      name: "RefundWindowClosed",
+      msg: "Refund window has closed",
+    },
+    {
+      code: 6061,
+      name: "InvalidRefundStatus",
+      msg: "Refund is not in a state that allows this action",
+    },
+    {
+      code: 6062,
+      name: "EscrowNotReleasable",
+      msg: "Escrow cannot be released yet",
+    },
+    {
+      code: 6063,
+      name: "MintNotAccepted",
+      msg: "Payment mint is not accepted by the platform",
+    },
+    {
+      code: 6064,
+      name: "TooManyAcceptedMints",
+      msg: "Too many accepted payment mints",
+    },
+    {
+      code: 6065,
+      name: "MissingTokenAccounts",
+      msg: "SPL token payment accounts are missing",
+    },
+    {
+      code: 6066,
+      name: "MissingNftAccounts",
+      msg: "Purchase NFT accounts are missing",
+    },
+    {
+      code: 6067,
+      name: "InvalidTokenAccount",
+      msg: "Token account does not match the payment mint or owner",
+    },
+    {
+      code: 6068,
+      name: "TokenRefundsUnsupported",
+      msg: "Refund windows are only supported for content priced in SOL",
+    },
+    {
+      code: 6069,
+      name: "TokenEscrowUnsupported",
+      msg: "Auctions and offers are only supported for content priced in SOL",
+    },
+    {
+      code: 6070,
+      name: "PaymentMintMismatch",
+      msg: "Payment mints do not match",
+    },
+    {
+      code: 6071,
+      name: "IncompatiblePricingModes",
+      msg: "USD pricing cannot be combined with declining prices or token payments",
+    },
+    {
+      code: 6072,
+      name: "InvalidPriceFeed",
+      msg: "Price feed is missing, does not match the content or is not trading",
+    },
+    {
+      code: 6073,
+      name: "StalePriceFeed",
+      msg: "Price feed is stale",
+    },
+    {
+      code: 6074,
+      name: "PriceConfidenceTooWide",
+      msg: "Price feed confidence interval is too wide",
+    },
+    {
+      code: 6075,
+      name: "PriceAboveMaximum",
+      msg: "Price exceeds the buyer's maximum",
+    },
+    {
+      code: 6076,
+      name: "InvalidNftMetadata",
+      msg: "NFT name, symbol or URI exceeds Metaplex limits",
+    },
+    {
+      code: 6077,
+      name: "NftCollectionExists",
+      msg: "Content already has an NFT collection",
+    },
+    {
+      code: 6078,
+      name: "NftsNotEnabled",
+      msg: "Content does not mint purchase NFTs",
+    },
+    {
+      code: 6079,
+      name: "NftAlreadyMinted",
+      msg: "An NFT has already been minted for this purchase",
+    },
+    {
+      code: 6080,
+      name: "NftBackedPurchase",
+      msg: "Purchase is held as an NFT and can only be resold through a listing",
+    },
+    {
+      code: 6081,
+      name: "NftNotHeld",
+      msg: "Purchase NFT is not held by this wallet",
+    },
+    {
+      code: 6082,
+      name: "RentalPassExists",
+      msg: "A rental pass has already been issued for this rental",
+    },
+    {
+      code: 6083,
+      name: "NoRentalPass",
+      msg: "Rental has no pass",
+    },
+    {
+      code: 6084,
+      name: "RentalNotExpired",
+      msg: "Rental has not expired yet",
+    },
+    {
+      code: 6085,
+      name: "RentalPassOutstanding",
+      msg: "Rental pass must be burned with burn_expired_rental first",
+    },
+    {
+      code: 6086,
+      name: "InvalidRentalPeriods",
+      msg: "Rental must be extended by at least one period",
+    },
+    {
+      code: 6087,
+      name: "StaleListing",
+      msg: "Listing was made for a copy the seller no longer holds",
+    },
+    {
+      code: 6088,
+      name: "ClaimWindowClosed",
+      msg: "Claim window for this revenue epoch has passed",
+    },
+    {
+      code: 6089,
+      name: "PriceOracleNotSet",
+      msg: "Platform has no price oracle for USD pricing",
+    },
   ],
 }
 
@@ -1,7 +1,10 @@
-import { Connection, PublicKey, SystemProgram } from "@solana/web3.js"
+import { Connection, PublicKey, SystemProgram, Transaction } from "@solana/web3.js"
 import { Program, AnchorProvider, BN } from "@project-serum/anchor"
 import { idl } from "../programs/slydr-program"
 
+// Matches RevenueEpoch::DURATION in the program
+const REVENUE_EPOCH_DURATION = 30 * 24 * 60 * 60
+
 export class AnchorService {
   private connection: Connection
   private provider: AnchorProvider | null = null
@@ -66,10 +69,17 @@ export class AnchorService {
   /**
    * Initialize the platform
    * @param authority The authority public key
-   * @param platformFee The platform fee (in lamports)
+   * @param platformFeeBps The platform fee (in basis points of each sale)
+   * @param minPlatformFee Optional minimum fee per sale (in lamports)
+   * @param maxPlatformFee Optional maximum fee per sale (in lamports)
    * @returns Transaction signature
    */
-  async initializePlatform(authority: PublicKey, platformFee: number): Promise<string> {
+  async initializePlatform(
+    authority: PublicKey,
+    platformFeeBps: number,
+    minPlatformFee?: number,
+    maxPlatformFee?: number,
+  ): Promise<string> {
     if (!this.isInitialized()) {
       throw new Error("Program not initialized")
     }
@@ -77,8 +87,12 @@ export class AnchorService {
     // Derive the platform PDA
     const [platformPda] = await PublicKey.findProgramAddress([Buffer.from("platform")], this.programId!)
 
+    // Prepare optional arguments
+    const minPlatformFeeOption = minPlatformFee !== undefined ? new BN(minPlatformFee) : null
+    const maxPlatformFeeOption = maxPlatformFee !== undefined ? new BN(maxPlatformFee) : null
+
     // Call the initialize instruction
-    const tx = await this.program!.methods.initialize(new BN(platformFee))
+    const tx = await this.program!.methods.initialize(platformFeeBps, minPlatformFeeOption, maxPlatformFeeOption)
       .accounts({
         platform: platformPda,
         authority,
@@ -100,6 +114,7 @@ export class AnchorService {
    * @param rentalPrice The rental price (in lamports)
    * @param rentalDuration The rental duration (in seconds)
    * @param subscriptionTier The subscription tier required (0 for none)
+   * @param maxSupply Optional edition cap (unlimited if omitted)
    * @returns Transaction signature
    */
   async createContent(
@@ -112,6 +127,7 @@ export class AnchorService {
     rentalPrice = 0,
     rentalDuration = 0,
     subscriptionTier = 0,
+    maxSupply?: number,
   ): Promise<string> {
     if (!this.isInitialized()) {
       throw new Error("Program not initialized")
@@ -126,20 +142,33 @@ export class AnchorService {
       this.programId!,
     )
 
+    // Convert the optional supply cap to BN or null
+    const maxSupplyOption = maxSupply !== undefined ? new BN(maxSupply) : null
+
+    // Derive the creator vault PDA
+    const [creatorVaultPda] = await PublicKey.findProgramAddress(
+      [Buffer.from("creator_vault"), creator.toBuffer()],
+      this.programId!,
+    )
+
     // Call the createContent instruction
     const tx = await this.program!.methods.createContent(
       contentId,
       arweaveId,
       new BN(price),
       royaltyPercentage,
-      rentalEnabled,
-      new BN(rentalPrice),
-      new BN(rentalDuration),
-      subscriptionTier,
+      {
+        rentalEnabled,
+        rentalPrice: new BN(rentalPrice),
+        rentalDuration: new BN(rentalDuration),
+        subscriptionTier,
+        maxSupply: maxSupplyOption,
+      },
     )
       .accounts({
         content: contentPda,
         creator,
+        creatorVault: creatorVaultPda,
         platform: platformPda,
         systemProgram: SystemProgram.programId,
       })
@@ -174,6 +203,9 @@ export class AnchorService {
       throw new Error("Program not initialized")
     }
 
+    // Derive the platform PDA
+    const [platformPda] = await PublicKey.findProgramAddress([Buffer.from("platform")], this.programId!)
+
     // Derive the content PDA
     const [contentPda] = await PublicKey.findProgramAddress(
       [Buffer.from("content"), Buffer.from(contentId)],
@@ -200,6 +232,7 @@ export class AnchorService {
       .accounts({
         content: contentPda,
         creator,
+        platform: platformPda,
         systemProgram: SystemProgram.programId,
       })
       .rpc()
@@ -211,9 +244,10 @@ export class AnchorService {
    * Purchase content
    * @param buyer The buyer's public key
    * @param contentId The content ID
+   * @param maxPrice Optional maximum price (in lamports) for USD-priced content
    * @returns Transaction signature
    */
-  async purchaseContent(buyer: PublicKey, contentId: string): Promise<string> {
+  async purchaseContent(buyer: PublicKey, contentId: string, maxPrice?: number): Promise<string> {
     if (!this.isInitialized()) {
       throw new Error("Program not initialized")
     }
@@ -227,9 +261,12 @@ export class AnchorService {
       this.programId!,
     )
 
-    // Get content data to find the creator
+    // Get content data to derive the creator's vault PDA
     const contentAccount = await this.program!.account.content.fetch(contentPda)
-    const creator = contentAccount.creator
+    const [creatorVaultPda] = await PublicKey.findProgramAddress(
+      [Buffer.from("creator_vault"), contentAccount.creator.toBuffer()],
+      this.programId!,
+    )
 
     // Derive the purchase PDA
     const [purchasePda] = await PublicKey.findProgramAddress(
@@ -237,15 +274,25 @@ export class AnchorService {
       this.programId!,
     )
 
+    // USD-priced content is converted at the platform's SOL/USD price feed
+    const priceFeed = contentAccount.usdPricing ? await this.getPlatformPriceFeed(platformPda) : null
+    const maxPriceOption = maxPrice !== undefined ? new BN(maxPrice) : null
+
     // Call the purchaseContent instruction
-    const tx = await this.program!.methods.purchaseContent()
+    const tx = await this.program!.methods.purchaseContent(maxPriceOption)
       .accounts({
         content: contentPda,
         buyer,
-        creator,
+        creatorVault: creatorVaultPda,
         platform: platformPda,
         purchase: purchasePda,
+        priceFeed,
         systemProgram: SystemProgram.programId,
+        // Paying in SOL, so no token accounts
+        buyerTokenAccount: null,
+        creatorVaultTokenAccount: null,
+        platformTokenAccount: null,
+        tokenProgram: null,
       })
       .rpc()
 
@@ -256,9 +303,10 @@ export class AnchorService {
    * Rent content
    * @param renter The renter's public key
    * @param contentId The content ID
+   * @param maxPrice Optional maximum price (in lamports) for USD-priced content
    * @returns Transaction signature
    */
-  async rentContent(renter: PublicKey, contentId: string): Promise<string> {
+  async rentContent(renter: PublicKey, contentId: string, maxPrice?: number): Promise<string> {
     if (!this.isInitialized()) {
       throw new Error("Program not initialized")
     }
@@ -272,9 +320,12 @@ export class AnchorService {
       this.programId!,
     )
 
-    // Get content data to find the creator
+    // Get content data to derive the creator's vault PDA
     const contentAccount = await this.program!.account.content.fetch(contentPda)
-    const creator = contentAccount.creator
+    const [creatorVaultPda] = await PublicKey.findProgramAddress(
+      [Buffer.from("creator_vault"), contentAccount.creator.toBuffer()],
+      this.programId!,
+    )
 
     // Derive the rental PDA
     const [rentalPda] = await PublicKey.findProgramAddress(
@@ -282,15 +333,25 @@ export class AnchorService {
       this.programId!,
     )
 
+    // USD-priced content is converted at the platform's SOL/USD price feed
+    const priceFeed = contentAccount.usdPricing ? await this.getPlatformPriceFeed(platformPda) : null
+    const maxPriceOption = maxPrice !== undefined ? new BN(maxPrice) : null
+
     // Call the rentContent instruction
-    const tx = await this.program!.methods.rentContent()
+    const tx = await this.program!.methods.rentContent(maxPriceOption)
       .accounts({
         content: contentPda,
         renter,
-        creator,
+        creatorVault: creatorVaultPda,
         platform: platformPda,
         rental: rentalPda,
+        priceFeed,
         systemProgram: SystemProgram.programId,
+        // Paying in SOL, so no token accounts
+        renterTokenAccount: null,
+        creatorVaultTokenAccount: null,
+        platformTokenAccount: null,
+        tokenProgram: null,
       })
       .rpc()
 
@@ -311,6 +372,19 @@ export class AnchorService {
     // Derive the platform PDA
     const [platformPda] = await PublicKey.findProgramAddress([Buffer.from("platform")], this.programId!)
 
+    // Derive the tier config PDA
+    const [tierConfigPda] = await PublicKey.findProgramAddress(
+      [Buffer.from("subscription_tier"), Buffer.from([tier])],
+      this.programId!,
+    )
+
+    // Derive the revenue pool PDA for the current 30-day epoch
+    const epoch = Math.floor(Date.now() / 1000 / REVENUE_EPOCH_DURATION)
+    const [revenueEpochPda] = await PublicKey.findProgramAddress(
+      [Buffer.from("revenue_epoch"), new BN(epoch).toArrayLike(Buffer, "le", 8)],
+      this.programId!,
+    )
+
     // Derive the subscription PDA
     const [subscriptionPda] = await PublicKey.findProgramAddress(
       [Buffer.from("subscription"), subscriber.toBuffer()],
@@ -322,8 +396,15 @@ export class AnchorService {
       .accounts({
         subscriber,
         platform: platformPda,
+        tierConfig: tierConfigPda,
+        revenueEpoch: revenueEpochPda,
+        tokenRevenuePool: null,
         subscription: subscriptionPda,
         systemProgram: SystemProgram.programId,
+        // Paying in SOL, so no token accounts
+        subscriberTokenAccount: null,
+        platformTokenAccount: null,
+        tokenProgram: null,
       })
       .rpc()
 
@@ -336,9 +417,9 @@ export class AnchorService {
    * @param buyer The buyer's public key
    * @param contentId The content ID
    * @param price The resale price (in lamports)
-   * @returns Transaction signature
+   * @returns Unsigned transaction that both the seller and the buyer must sign
    */
-  async resellContent(seller: PublicKey, buyer: PublicKey, contentId: string, price: number): Promise<string> {
+  async resellContent(seller: PublicKey, buyer: PublicKey, contentId: string, price: number): Promise<Transaction> {
     if (!this.isInitialized()) {
       throw new Error("Program not initialized")
     }
@@ -352,9 +433,12 @@ export class AnchorService {
       this.programId!,
     )
 
-    // Get content data to find the creator
+    // Get content data to derive the creator's vault PDA
     const contentAccount = await this.program!.account.content.fetch(contentPda)
-    const creator = contentAccount.creator
+    const [creatorVaultPda] = await PublicKey.findProgramAddress(
+      [Buffer.from("creator_vault"), contentAccount.creator.toBuffer()],
+      this.programId!,
+    )
 
     // Derive the seller's purchase PDA
     const [sellerPurchasePda] = await PublicKey.findProgramAddress(
@@ -362,29 +446,55 @@ export class AnchorService {
       this.programId!,
     )
 
+    // Derive the seller's listing PDA, closed with the sale if the copy was listed
+    const [sellerListingPda] = await PublicKey.findProgramAddress(
+      [Buffer.from("listing"), sellerPurchasePda.toBuffer()],
+      this.programId!,
+    )
+
     // Derive the buyer's purchase PDA
     const [buyerPurchasePda] = await PublicKey.findProgramAddress(
       [Buffer.from("purchase"), buyer.toBuffer(), contentPda.toBuffer()],
       this.programId!,
     )
 
-    // Call the resellContent instruction
+    // Build the resellContent transaction; the buyer pays the fees
     const tx = await this.program!.methods.resellContent(new BN(price))
       .accounts({
         content: contentPda,
         seller,
         buyer,
-        creator,
+        creatorVault: creatorVaultPda,
         platform: platformPda,
         sellerPurchase: sellerPurchasePda,
+        sellerListing: sellerListingPda,
         buyerPurchase: buyerPurchasePda,
         systemProgram: SystemProgram.programId,
+        // Paying in SOL, so no token accounts
+        buyerTokenAccount: null,
+        creatorVaultTokenAccount: null,
+        sellerTokenAccount: null,
+        platformTokenAccount: null,
+        tokenProgram: null,
       })
-      .rpc()
+      .transaction()
+
+    tx.feePayer = buyer
+    tx.recentBlockhash = (await this.connection.getLatestBlockhash()).blockhash
 
     return tx
   }
 
+  /**
+   * Get the SOL/USD price feed USD prices convert at
+   * @param platformPda The platform PDA
+   * @returns Price feed public key, or null if the platform has no price oracle
+   */
+  private async getPlatformPriceFeed(platformPda: PublicKey): Promise<PublicKey | null> {
+    const platformAccount = await this.program!.account.platform.fetch(platformPda)
+    return platformAccount.priceOracle ? platformAccount.priceOracle.solUsdFeed : null
+  }
+
   /**
    * Get content details
    * @param contentId The content ID